@@ -0,0 +1,89 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+use crate::path::Path;
+
+use nohash_hasher::IntMap;
+
+/*
+    Bidirectional breadth-first search 
+
+    Finds the fewest-hop route between two nodes by growing two breadth-first
+    frontiers - one forward from the source along Go edges, one backward from 
+    the target along the reverse of Go edges (the NoGo side of one way edges) - 
+    until the frontiers meet. The smaller frontier is always expanded next.
+
+*/
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{ 
+    /// Returns a shortest unweighted route from source to target; its len is the number of hops.
+    /// Hop costs are those of the cheapest Go edge between each pair of nodes.
+    /// Returns None if either node does not exist or target cannot be reached.
+    pub fn bf_bidirectional(&self, source : Id, target : Id) -> Option<Path<Id, Cost>> {
+
+        if !self.nodes.contains_key(&source) || !self.nodes.contains_key(&target) {
+            return None;
+        }
+
+        if source == target {
+            return Some(Path::new(source));
+        }
+
+        // Each side maps a visited node to the node it was reached from
+        let mut forward = IntMap::<Id, Id>::default();
+        let mut backward = IntMap::<Id, Id>::default();
+        forward.insert(source, source);
+        backward.insert(target, target);
+
+        let mut forward_frontier = vec![source];
+        let mut backward_frontier = vec![target];
+
+        let mut meet = None;
+
+        while meet.is_none() && !forward_frontier.is_empty() && !backward_frontier.is_empty() {
+
+            if forward_frontier.len() <= backward_frontier.len() {
+                let mut next = Vec::new();
+                for &id in forward_frontier.iter() {
+                    for &neighbour in self.nodes[&id].neighbours() {
+                        if forward.contains_key(&neighbour) { continue; }
+                        forward.insert(neighbour, id);
+                        next.push(neighbour);
+                        if meet.is_none() && backward.contains_key(&neighbour) { meet = Some(neighbour); }
+                    }
+                }
+                forward_frontier = next;
+            } else {
+                let mut next = Vec::new();
+                for &id in backward_frontier.iter() {
+                    for neighbour in self.predecessors(id) {
+                        if backward.contains_key(&neighbour) { continue; }
+                        backward.insert(neighbour, id);
+                        next.push(neighbour);
+                        if meet.is_none() && forward.contains_key(&neighbour) { meet = Some(neighbour); }
+                    }
+                }
+                backward_frontier = next;
+            }
+        }
+
+        let meet = meet?;
+
+        // Walk back to the source, then on to the target
+        let mut path = vec![meet];
+        let mut current = meet;
+        while current != source {
+            current = forward[&current];
+            path.push(current);
+        }
+        path.reverse();
+
+        let mut current = meet;
+        while current != target {
+            current = backward[&current];
+            path.push(current);
+        }
+
+        Some(Path::along(self, &path).expect("Every hop was found along a Go edge"))
+    }
+}
@@ -1,3 +1,5 @@
 pub mod breadth_first_into_iter;
 pub mod breadth_first_iter;
-pub mod breadth_first_search;
\ No newline at end of file
+pub mod breadth_first_search;
+pub mod bidirectional_breadth_first_search;
+pub mod parallel_breadth_first;
\ No newline at end of file
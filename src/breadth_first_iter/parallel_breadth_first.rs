@@ -0,0 +1,94 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+
+use nohash_hasher::IntMap;
+use std::collections::hash_map::Entry;
+use std::thread;
+
+/*
+    Parallel breadth-first traversal
+
+    A level-synchronous breadth-first traversal. Each frontier is split into
+    chunks that worker threads expand against the nodes already seen; the
+    candidates are then merged in chunk order, so the result matches a 
+    sequential breadth-first traversal exactly.
+
+*/
+
+// Frontiers smaller than this are expanded on the calling thread
+const MIN_PARALLEL_FRONTIER: usize = 1024;
+
+/// The depth of every node reached by a breadth-first traversal, and the node each was reached from
+pub struct BreadthFirstTree<Id> where Id : Identity
+{
+    pub depths: IntMap<Id, usize>,
+    /// The source has no parent
+    pub parents: IntMap<Id, Id>
+}
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity + Send + Sync, Cost : Scalar + Sync
+{ 
+    /// Traverses everything reachable from id breadth first, spreading each level across up to 
+    /// threads worker threads. 
+    /// Will return an empty tree if graph does not contain node of id
+    pub fn bf_parallel(&self, id : Id, threads : usize) -> BreadthFirstTree<Id> {
+        let mut depths = IntMap::<Id, usize>::default();
+        let mut parents = IntMap::<Id, Id>::default();
+
+        if !self.nodes.contains_key(&id) {
+            return BreadthFirstTree { depths, parents };
+        }
+
+        depths.insert(id, 0);
+        let mut frontier = vec![id];
+        let mut depth = 0;
+
+        while !frontier.is_empty() {
+            depth += 1;
+
+            let candidates : Vec<Vec<(Id, Id)>> = if threads <= 1 || frontier.len() < MIN_PARALLEL_FRONTIER {
+                vec![self.expand(&frontier, &depths)]
+            } else {
+                let chunk_size = frontier.len().div_ceil(threads);
+                let seen = &depths;
+
+                thread::scope(|scope| {
+                    let workers : Vec<_> = frontier
+                        .chunks(chunk_size)
+                        .map(|chunk| scope.spawn(move || self.expand(chunk, seen)))
+                        .collect();
+
+                    workers
+                        .into_iter()
+                        .map(|worker| worker.join().expect("Breadth-first worker panicked"))
+                        .collect()
+                })
+            };
+
+            // Chunks are merged in frontier order, so the first parent to claim a node wins
+            let mut next = Vec::new();
+            for (child, parent) in candidates.into_iter().flatten() {
+                if let Entry::Vacant(v) = depths.entry(child) {
+                    v.insert(depth);
+                    parents.insert(child, parent);
+                    next.push(child);
+                }
+            }
+
+            frontier = next;
+        }
+
+        BreadthFirstTree { depths, parents }
+    }
+
+    /// Returns every (neighbour, parent) pair leaving the chunk that has not yet been seen
+    fn expand(&self, chunk : &[Id], seen : &IntMap<Id, usize>) -> Vec<(Id, Id)> {
+        chunk
+            .iter()
+            .flat_map(|parent| self.nodes[parent]
+                .neighbours()
+                .filter(|&child| !seen.contains_key(child))
+                .map(move |child| (*child, *parent)))
+            .collect()
+    }
+}
@@ -0,0 +1,243 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+use crate::scored::MinScored;
+use crate::shortest_path::shortest_paths::NegativeCostError;
+
+use nohash_hasher::{IntMap, IntSet};
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt;
+
+/*
+    Contraction hierarchy
+
+    Preprocessing for fast repeated shortest path queries along Go edges. Nodes
+    are contracted one at a time, least important first - a contracted node is
+    removed, and a shortcut edge is added between each pair of its neighbours 
+    whose shortest route ran through it. Every query then only needs to search
+    upwards through the ranks from both ends.
+
+    The importance of a node is the number of shortcuts its contraction would 
+    add, less the edges it would remove, plus the number of its neighbours 
+    already contracted (to spread contraction evenly). Importance is updated
+    lazily as nodes are taken. Costs must be non-negative.
+
+*/
+
+// Witness searches give up after settling this many nodes and add the shortcut anyway
+const WITNESS_SETTLE_LIMIT: usize = 64;
+
+/// An edge of the hierarchy, which may be a shortcut through a contracted node
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct HierarchyEdge<Id, Cost> {
+    pub(crate) to: Id,
+    pub(crate) cost: Cost
+}
+
+/// A graph prepared for fast shortest path queries. Only valid for the graph it was built from,
+/// in the state it was built in.
+pub struct ContractionHierarchy<Id, Cost> where Id : Identity
+{
+    /// The order each node was contracted in
+    pub(crate) rank: IntMap<Id, usize>,
+    /// Go edges leading to higher ranked nodes
+    pub(crate) upward: IntMap<Id, Vec<HierarchyEdge<Id, Cost>>>,
+    /// Go edges arriving from higher ranked nodes, reversed
+    pub(crate) downward: IntMap<Id, Vec<HierarchyEdge<Id, Cost>>>,
+    /// The contracted node each shortcut passes through
+    pub(crate) middles: HashMap<(Id, Id), Id>,
+    pub(crate) version: u64,
+    pub(crate) fingerprint: u64
+}
+
+/// Returned when a hierarchy is queried after the graph it was built from has changed
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct StaleHierarchyError;
+
+impl fmt::Display for StaleHierarchyError {
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the graph has changed since the contraction hierarchy was built")
+    }
+}
+
+impl std::error::Error for StaleHierarchyError {}
+
+impl<Id, Cost> ContractionHierarchy<Id, Cost> where Id : Identity, Cost : Scalar
+{
+    /// The number of shortcut edges added
+    pub fn shortcut_count(&self) -> usize {
+        self.middles.len()
+    }
+
+    /// The order id was contracted in, lowest first
+    pub fn rank(&self, id : Id) -> Option<usize> {
+        self.rank.get(&id).copied()
+    }
+
+    /// Returns an error if graph is not the graph, in the same state, that this was built from.
+    /// Only compares versions, so changes made directly through nodes are not seen unless
+    /// followed by graph.touch(). Every query makes this check.
+    pub fn check_current(&self, graph : &Graph<Id, Cost>) -> Result<(), StaleHierarchyError> {
+        match graph.version() == self.version {
+            true => Ok(()),
+            false => Err(StaleHierarchyError)
+        }
+    }
+
+    /// As check_current, but also compares a fingerprint of every node and edge, so catches
+    /// changes made directly through nodes. Costs a pass over the whole graph.
+    pub fn verify_thoroughly(&self, graph : &Graph<Id, Cost>) -> Result<(), StaleHierarchyError> {
+        self.check_current(graph)?;
+        match graph.fingerprint() == self.fingerprint {
+            true => Ok(()),
+            false => Err(StaleHierarchyError)
+        }
+    }
+}
+
+/// The graph still left to contract, with at most one (the cheapest) edge per pair of nodes
+struct Overlay<Id, Cost> where Id : Identity
+{
+    outgoing: IntMap<Id, IntMap<Id, Cost>>,
+    incoming: IntMap<Id, IntMap<Id, Cost>>,
+    contracted_neighbours: IntMap<Id, usize>
+}
+
+impl<Id, Cost> Overlay<Id, Cost> where Id : Identity, Cost : Scalar
+{
+    /// Adds the edge unless an edge at least as cheap already joins the pair
+    fn add_edge(&mut self, from : Id, to : Id, cost : Cost) -> bool {
+        let outgoing = self.outgoing.get_mut(&from).expect("Overlay holds every node");
+        if outgoing.get(&to).is_some_and(|&current| current <= cost) {
+            return false;
+        }
+
+        outgoing.insert(to, cost);
+        self.incoming.get_mut(&to).expect("Overlay holds every node").insert(from, cost);
+        true
+    }
+
+    /// Returns true if a route from source to target avoiding via costs no more than limit
+    fn has_witness(&self, source : Id, target : Id, via : Id, limit : Cost) -> bool {
+        let mut distances = IntMap::<Id, Cost>::default();
+        let mut settled = IntSet::<Id>::default();
+        let mut heap = BinaryHeap::new();
+
+        distances.insert(source, Cost::default());
+        heap.push(MinScored(Cost::default(), source));
+
+        while let Some(MinScored(distance, id)) = heap.pop() {
+            if distance > limit { return false; }
+            if id == target { return true; }
+            if !settled.insert(id) { continue; }
+            if settled.len() > WITNESS_SETTLE_LIMIT { return false; }
+
+            for (&neighbour, &cost) in self.outgoing[&id].iter() {
+                if neighbour == via { continue; }
+
+                let next = distance + cost;
+                if distances.get(&neighbour).is_none_or(|&d| next < d) {
+                    distances.insert(neighbour, next);
+                    heap.push(MinScored(next, neighbour));
+                }
+            }
+        }
+
+        false
+    }
+
+    /// The shortcuts contracting id needs: (from, to, cost)
+    fn shortcuts(&self, id : Id) -> Vec<(Id, Id, Cost)> {
+        let mut shortcuts = Vec::new();
+
+        for (&from, &in_cost) in self.incoming[&id].iter() {
+            for (&to, &out_cost) in self.outgoing[&id].iter() {
+                if from == to { continue; }
+
+                let through = in_cost + out_cost;
+                if !self.has_witness(from, to, id, through) {
+                    shortcuts.push((from, to, through));
+                }
+            }
+        }
+
+        shortcuts
+    }
+
+    fn importance(&self, id : Id) -> isize {
+        let removed = self.incoming[&id].len() + self.outgoing[&id].len();
+        self.shortcuts(id).len() as isize - removed as isize + self.contracted_neighbours[&id] as isize
+    }
+}
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{ 
+    /// Builds a contraction hierarchy over the graph as it stands.
+    pub fn contraction_hierarchy(&self) -> Result<ContractionHierarchy<Id, Cost>, NegativeCostError<Id, Cost>> {
+        let mut overlay = Overlay {
+            outgoing: self.nodes.keys().map(|&id| (id, IntMap::default())).collect(),
+            incoming: self.nodes.keys().map(|&id| (id, IntMap::default())).collect(),
+            contracted_neighbours: self.nodes.keys().map(|&id| (id, 0)).collect()
+        };
+
+        for (&from, node) in self.nodes.iter() {
+            for (&to, &cost) in node.weighted_neighbours() {
+                if cost < Cost::default() {
+                    return Err(NegativeCostError { from, to, cost });
+                }
+                if from != to {
+                    overlay.add_edge(from, to, cost);
+                }
+            }
+        }
+
+        let mut hierarchy = ContractionHierarchy {
+            rank: IntMap::default(),
+            upward: IntMap::default(),
+            downward: IntMap::default(),
+            middles: HashMap::new(),
+            version: self.version(),
+            fingerprint: self.fingerprint()
+        };
+
+        let mut queue : BinaryHeap<MinScored<isize, Id>> = self.nodes
+            .keys()
+            .map(|&id| MinScored(overlay.importance(id), id))
+            .collect();
+
+        while let Some(MinScored(importance, id)) = queue.pop() {
+
+            // Importance goes stale as neighbours are contracted - only take the node if it is
+            // still the least important
+            let current = overlay.importance(id);
+            if current != importance && queue.peek().is_some_and(|MinScored(next, _)| current > *next) {
+                queue.push(MinScored(current, id));
+                continue;
+            }
+
+            for (from, to, cost) in overlay.shortcuts(id) {
+                if overlay.add_edge(from, to, cost) {
+                    hierarchy.middles.insert((from, to), id);
+                }
+            }
+
+            // Everything still in the overlay will be ranked higher
+            let outgoing = overlay.outgoing.remove(&id).expect("Overlay holds every node");
+            let incoming = overlay.incoming.remove(&id).expect("Overlay holds every node");
+
+            for (&to, _) in outgoing.iter() {
+                overlay.incoming.get_mut(&to).expect("Overlay holds every node").remove(&id);
+                *overlay.contracted_neighbours.get_mut(&to).expect("Overlay holds every node") += 1;
+            }
+            for (&from, _) in incoming.iter() {
+                overlay.outgoing.get_mut(&from).expect("Overlay holds every node").remove(&id);
+                *overlay.contracted_neighbours.get_mut(&from).expect("Overlay holds every node") += 1;
+            }
+
+            hierarchy.rank.insert(id, hierarchy.rank.len());
+            hierarchy.upward.insert(id, outgoing.into_iter().map(|(to, cost)| HierarchyEdge { to, cost }).collect());
+            hierarchy.downward.insert(id, incoming.into_iter().map(|(to, cost)| HierarchyEdge { to, cost }).collect());
+        }
+
+        Ok(hierarchy)
+    }
+}
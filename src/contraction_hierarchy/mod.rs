@@ -0,0 +1,2 @@
+pub mod hierarchy;
+pub mod query;
@@ -0,0 +1,164 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+use crate::path::Path;
+use crate::scored::MinScored;
+use crate::contraction_hierarchy::hierarchy::{ContractionHierarchy, HierarchyEdge, StaleHierarchyError};
+
+use nohash_hasher::IntMap;
+use std::collections::BinaryHeap;
+
+/*
+    Contraction hierarchy queries
+
+    Two Dijkstra searches that only ever climb in rank - forward from the source
+    over upward edges, backward from the target over downward edges. The shortest
+    route peaks at a node both searches reach. Shortcuts on the route are then 
+    unpacked back into the original Go edges.
+
+*/
+
+struct UpwardSearch<Id, Cost> where Id : Identity
+{
+    distances: IntMap<Id, Cost>,
+    parents: IntMap<Id, Id>,
+    heap: BinaryHeap<MinScored<Cost, Id>>
+}
+
+impl<Id, Cost> UpwardSearch<Id, Cost> where Id : Identity, Cost : Scalar
+{
+    fn new(id : Id) -> UpwardSearch<Id, Cost> {
+        let mut search = UpwardSearch { distances: IntMap::default(), parents: IntMap::default(), heap: BinaryHeap::new() };
+        search.distances.insert(id, Cost::default());
+        search.heap.push(MinScored(Cost::default(), id));
+        search
+    }
+
+    /// Settles the next node, returning it. Returns None once nothing cheaper than bound is left.
+    fn step(&mut self, edges : &IntMap<Id, Vec<HierarchyEdge<Id, Cost>>>, bound : Option<Cost>) -> Option<Id> {
+        while let Some(MinScored(distance, id)) = self.heap.pop() {
+            if bound.is_some_and(|bound| distance >= bound) { 
+                self.heap.clear();
+                return None; 
+            }
+            if self.distances[&id] < distance { continue; }
+
+            for edge in edges[&id].iter() {
+                let next = distance + edge.cost;
+                if self.distances.get(&edge.to).is_none_or(|&d| next < d) {
+                    self.distances.insert(edge.to, next);
+                    self.parents.insert(edge.to, id);
+                    self.heap.push(MinScored(next, edge.to));
+                }
+            }
+
+            return Some(id);
+        }
+
+        None
+    }
+}
+
+/// Where the two searches met on a shortest route, and the searches themselves
+struct Meeting<Id, Cost> where Id : Identity
+{
+    cost: Cost,
+    meet: Id,
+    forward: UpwardSearch<Id, Cost>,
+    backward: UpwardSearch<Id, Cost>
+}
+
+impl<Id, Cost> ContractionHierarchy<Id, Cost> where Id : Identity, Cost : Scalar
+{
+    /// Returns the shortest distance from source to target, or None if target can not be reached.
+    /// Errors if graph has changed since the hierarchy was built from it, see check_current.
+    pub fn distance(&self, graph : &Graph<Id, Cost>, source : Id, target : Id) -> Result<Option<Cost>, StaleHierarchyError> {
+        self.check_current(graph)?;
+        Ok(self.meet(source, target).map(|meeting| meeting.cost))
+    }
+
+    /// Returns the shortest path in original Go edges, or None if target can not be reached.
+    /// Errors if graph has changed since the hierarchy was built from it, see check_current.
+    pub fn shortest_path(&self, graph : &Graph<Id, Cost>, source : Id, target : Id) -> Result<Option<Path<Id, Cost>>, StaleHierarchyError> {
+        self.check_current(graph)?;
+
+        let Some(Meeting { meet, forward, backward, .. }) = self.meet(source, target) else { return Ok(None) };
+
+        // The route through the hierarchy, which may include shortcuts
+        let mut route = vec![meet];
+        let mut current = meet;
+        while let Some(&parent) = forward.parents.get(&current) {
+            route.push(parent);
+            current = parent;
+        }
+        route.reverse();
+
+        let mut current = meet;
+        while let Some(&parent) = backward.parents.get(&current) {
+            route.push(parent);
+            current = parent;
+        }
+
+        let mut path = vec![source];
+        for hop in route.windows(2) {
+            self.unpack(hop[0], hop[1], &mut path);
+        }
+
+        Ok(Some(Path::along(graph, &path).expect("Every hop of a path is a Go edge")))
+    }
+
+    fn meet(&self, source : Id, target : Id) -> Option<Meeting<Id, Cost>> {
+        if !self.rank.contains_key(&source) || !self.rank.contains_key(&target) {
+            return None;
+        }
+
+        let mut forward = UpwardSearch::<Id, Cost>::new(source);
+        let mut backward = UpwardSearch::<Id, Cost>::new(target);
+        let mut best : Option<(Cost, Id)> = None;
+
+        let mut forward_done = false;
+        let mut backward_done = false;
+
+        while !forward_done || !backward_done {
+            let bound = best.map(|(cost, _)| cost);
+
+            if !forward_done {
+                match forward.step(&self.upward, bound) {
+                    Some(id) => if let Some(&remaining) = backward.distances.get(&id) {
+                        let through = forward.distances[&id] + remaining;
+                        if best.is_none_or(|(cost, _)| through < cost) { best = Some((through, id)); }
+                    },
+                    None => forward_done = true
+                }
+            }
+
+            let bound = best.map(|(cost, _)| cost);
+
+            if !backward_done {
+                match backward.step(&self.downward, bound) {
+                    Some(id) => if let Some(&remaining) = forward.distances.get(&id) {
+                        let through = backward.distances[&id] + remaining;
+                        if best.is_none_or(|(cost, _)| through < cost) { best = Some((through, id)); }
+                    },
+                    None => backward_done = true
+                }
+            }
+        }
+
+        best.map(|(cost, meet)| Meeting { cost, meet, forward, backward })
+    }
+
+    /// Appends the original nodes after from on the hierarchy edge from -> to
+    fn unpack(&self, from : Id, to : Id, path : &mut Vec<Id>) {
+        let mut stack = vec![(from, to)];
+
+        while let Some((a, b)) = stack.pop() {
+            match self.middles.get(&(a, b)) {
+                Some(&middle) => {
+                    stack.push((middle, b));
+                    stack.push((a, middle));
+                },
+                None => path.push(b)
+            }
+        }
+    }
+}
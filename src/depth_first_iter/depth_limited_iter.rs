@@ -0,0 +1,88 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+
+use nohash_hasher::{IntMap, IntSet};
+
+/*
+    Depth-limited depth-first iteration
+
+    A depth-first iterator that never travels more than max_depth hops from the
+    source. A node first reached along a long branch is revisited if a shorter
+    branch reaches it later, so everything within max_depth hops is found.
+
+*/
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{ 
+    /// Returns a depth first iterator yielding (id, depth) for every node within max_depth hops 
+    /// of id, where depth is the depth the node was first found at.
+    /// Will return an empty iterator if graph does not contain node of id
+    pub fn df_iter_limited(&self, id : Id, max_depth : usize) -> DepthLimitedIter<'_, Id, Cost> {
+        DepthLimitedIter::<Id, Cost>::new(id, self, max_depth)
+    }
+}
+
+pub struct DepthLimitedIter<'a, Id, Cost> where Id : Identity
+{
+    graph: &'a Graph<Id, Cost>,
+    stack: Vec<(Id, usize)>,
+    depths: IntMap<Id, usize>,
+    set: IntSet<Id>,
+    max_depth: usize,
+    beyond: Vec<Id>
+}
+
+impl<'a, Id, Cost> DepthLimitedIter<'a, Id, Cost> where Id : Identity
+{
+    pub fn new(id : Id, graph : &'a Graph<Id, Cost>, max_depth : usize) -> DepthLimitedIter<'a, Id, Cost> {
+        let mut stack = Vec::<(Id, usize)>::new();
+        if graph.nodes.contains_key(&id) {
+            stack.push((id, 0));
+        }
+
+        let mut depths = IntMap::<Id, usize>::default();
+        depths.insert(id, 0);
+
+        DepthLimitedIter { graph, stack, depths, set: IntSet::default(), max_depth, beyond: Vec::new() }
+    }
+
+    /// Returns true if a node at max_depth has neighbours that lie beyond max_depth.
+    /// Only conclusive once the iterator is exhausted.
+    pub fn cut_off(&self) -> bool {
+        self.beyond.iter().any(|id| !self.depths.contains_key(id))
+    }
+}
+
+impl<'a, Id, Cost> Iterator for DepthLimitedIter<'a, Id, Cost> where Id : Identity
+{
+    type Item = (Id, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+
+        while let Some((id, depth)) = self.stack.pop() {
+
+            // A shorter branch has since reached this node
+            if depth > self.depths[&id] { continue; }
+
+            let neighbours = self.graph.nodes[&id].neighbours();
+
+            if depth == self.max_depth {
+                // Unknown for now, but may yet be reached by a shorter branch
+                self.beyond.extend(neighbours.filter(|&n| !self.depths.contains_key(n)));
+            } else {
+                for &neighbour in neighbours {
+                    if self.depths.get(&neighbour).is_none_or(|&d| depth + 1 < d) {
+                        self.depths.insert(neighbour, depth + 1);
+                        self.stack.push((neighbour, depth + 1));
+                    }
+                }
+            }
+
+            if self.set.insert(id) {
+                return Some((id, depth));
+            }
+        }
+
+        None
+    }
+}
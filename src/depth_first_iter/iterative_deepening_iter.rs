@@ -0,0 +1,68 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+use crate::depth_first_iter::depth_limited_iter::DepthLimitedIter;
+
+use nohash_hasher::IntSet;
+
+/*
+    Iterative-deepening depth-first iteration
+
+    Repeats a depth-limited depth-first iteration with the limit growing by one
+    each pass, yielding each node on the pass that first reaches it. Nodes are 
+    therefore yielded in order of increasing hop distance, while only ever
+    holding a depth-first stack in memory.
+
+*/
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{ 
+    /// Returns an iterator yielding (id, depth) for every node reachable from id, in order of 
+    /// increasing depth, where depth is the fewest hops needed to reach the node.
+    /// Will return an empty iterator if graph does not contain node of id
+    pub fn df_iter_deepening(&self, id : Id) -> IterativeDeepeningIter<'_, Id, Cost> {
+        IterativeDeepeningIter::<Id, Cost>::new(id, self)
+    }
+}
+
+pub struct IterativeDeepeningIter<'a, Id, Cost> where Id : Identity
+{
+    graph: &'a Graph<Id, Cost>,
+    id: Id,
+    pass: DepthLimitedIter<'a, Id, Cost>,
+    set: IntSet<Id>,
+    limit: usize
+}
+
+impl<'a, Id, Cost> IterativeDeepeningIter<'a, Id, Cost> where Id : Identity
+{
+    pub fn new(id : Id, graph : &'a Graph<Id, Cost>) -> IterativeDeepeningIter<'a, Id, Cost> {
+        let pass = DepthLimitedIter::new(id, graph, 0);
+
+        IterativeDeepeningIter { graph, id, pass, set: IntSet::default(), limit: 0 }
+    }
+
+    /// The depth limit of the current pass
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+impl<'a, Id, Cost> Iterator for IterativeDeepeningIter<'a, Id, Cost> where Id : Identity
+{
+    type Item = (Id, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+
+        loop {
+            match self.pass.next() {
+                Some((id, depth)) => if self.set.insert(id) { return Some((id, depth)) },
+                // Nothing was left beyond the limit - the whole component has been seen
+                None if !self.pass.cut_off() => return None,
+                None => {
+                    self.limit += 1;
+                    self.pass = DepthLimitedIter::new(self.id, self.graph, self.limit);
+                }
+            }
+        }
+    }
+}
@@ -1,2 +1,4 @@
 pub mod depth_first_iter;
-pub mod depth_first_into_iter;
\ No newline at end of file
+pub mod depth_first_into_iter;
+pub mod depth_limited_iter;
+pub mod iterative_deepening_iter;
\ No newline at end of file
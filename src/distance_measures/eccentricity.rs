@@ -0,0 +1,216 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+use crate::path::Path;
+use crate::subgraph::ego_graph::Metric;
+use crate::shortest_path::shortest_paths::NegativeCostError;
+
+use nohash_hasher::IntMap;
+use std::collections::VecDeque;
+
+/*
+    Eccentricity and diameter
+
+    The eccentricity of a node is the distance to the node furthest from it, 
+    along Go edges. The diameter and radius are the largest and smallest 
+    eccentricities; the center and periphery are the nodes attaining them. A 
+    node that can not reach every other node has infinite eccentricity. 
+    
+    The exact measures search from every node. The double sweep searches twice: 
+    out from a start node, then out from the node found furthest away - giving a 
+    lower bound on the diameter that is often exact on sparse graphs such as trees.
+    Cost distances require non-negative costs, and are kept in the cost type so 
+    large integer costs compare exactly.
+
+*/
+
+/// A distance in the metric searched with. Distances are only compared within one metric, 
+/// where infinite is the furthest of all.
+#[derive(PartialEq, PartialOrd, Clone, Copy, Debug)]
+pub enum Length<Cost> {
+    Hops(usize),
+    Cost(Cost),
+    /// Some node can not be reached
+    Infinite
+}
+
+impl<Cost : Scalar> Length<Cost> {
+    /// Lossy conversion, for comparing lengths against plain numbers
+    pub fn as_f64(self) -> f64 {
+        match self {
+            Length::Hops(hops) => hops as f64,
+            Length::Cost(cost) => cost.as_f64(),
+            Length::Infinite => f64::INFINITY
+        }
+    }
+}
+
+/// The eccentricity of every node
+#[derive(Clone, Debug)]
+pub struct Eccentricities<Id, Cost> where Id : Identity
+{
+    pub values: IntMap<Id, Length<Cost>>
+}
+
+impl<Id, Cost> Eccentricities<Id, Cost> where Id : Identity, Cost : Scalar
+{
+    /// The eccentricity of id, if graph contains it
+    pub fn get(&self, id : Id) -> Option<Length<Cost>> {
+        self.values.get(&id).copied()
+    }
+
+    /// The largest eccentricity, None for an empty graph
+    pub fn diameter(&self) -> Option<Length<Cost>> {
+        self.values.values().copied().reduce(|a, b| if b > a { b } else { a })
+    }
+
+    /// The smallest eccentricity, None for an empty graph
+    pub fn radius(&self) -> Option<Length<Cost>> {
+        self.values.values().copied().reduce(|a, b| if b < a { b } else { a })
+    }
+
+    /// Every node whose eccentricity equals the radius
+    pub fn center(&self) -> Vec<Id> {
+        self.attaining(self.radius())
+    }
+
+    /// Every node whose eccentricity equals the diameter
+    pub fn periphery(&self) -> Vec<Id> {
+        self.attaining(self.diameter())
+    }
+
+    fn attaining(&self, value : Option<Length<Cost>>) -> Vec<Id> {
+        self.values.iter().filter(|(_, &e)| Some(e) == value).map(|(&id, _)| id).collect()
+    }
+}
+
+/// A longest shortest path, between its two endpoints
+#[derive(PartialEq, Clone, Debug)]
+pub struct Diameter<Id, Cost>
+{
+    /// The distance between the endpoints, in the metric searched with
+    pub length: Length<Cost>,
+    pub endpoints: (Id, Id),
+    pub path: Path<Id, Cost>
+}
+
+/// Distances out of a source and the predecessor of each node reached
+type Reach<Id, Cost> = (IntMap<Id, Length<Cost>>, IntMap<Id, Id>);
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{ 
+    /// Returns the eccentricity of id, or None if graph does not contain id
+    pub fn eccentricity(&self, id : Id, metric : Metric) -> Result<Option<Length<Cost>>, NegativeCostError<Id, Cost>> {
+        if !self.nodes.contains_key(&id) { return Ok(None); }
+
+        let (distances, _) = self.reach(id, metric)?;
+        Ok(Some(self.furthest_of(&distances)))
+    }
+
+    /// Returns the eccentricity of every node. Searches from every node.
+    pub fn eccentricities(&self, metric : Metric) -> Result<Eccentricities<Id, Cost>, NegativeCostError<Id, Cost>> {
+        let mut values = IntMap::<Id, Length<Cost>>::default();
+
+        for &id in self.nodes.keys() {
+            let (distances, _) = self.reach(id, metric)?;
+            values.insert(id, self.furthest_of(&distances));
+        }
+
+        Ok(Eccentricities { values })
+    }
+
+    /// Returns a longest shortest path in graph. Searches from every node.
+    /// Returns None if graph is empty or some node can not reach every other.
+    pub fn diameter(&self, metric : Metric) -> Result<Option<Diameter<Id, Cost>>, NegativeCostError<Id, Cost>> {
+        // The longest eccentricity, and the node it belongs to
+        let mut best : Option<(Length<Cost>, Id)> = None;
+
+        for &id in self.nodes.keys() {
+            let (distances, _) = self.reach(id, metric)?;
+            if distances.len() < self.nodes.len() { return Ok(None); }
+
+            let length = self.furthest_of(&distances);
+            if best.is_none_or(|(longest, _)| length > longest) {
+                best = Some((length, id));
+            }
+        }
+
+        let Some((_, source)) = best else { return Ok(None) };
+        self.longest_from(source, metric)
+    }
+
+    /// Returns a long shortest path found by a double sweep from start - a lower bound on the 
+    /// diameter. Searches twice; the longer sweep is kept, as on one way edges the second may 
+    /// fall short of the first. Returns None if graph does not contain start.
+    pub fn diameter_double_sweep(&self, start : Id, metric : Metric) -> Result<Option<Diameter<Id, Cost>>, NegativeCostError<Id, Cost>> {
+        let Some(first) = self.longest_from(start, metric)? else { return Ok(None) };
+        let second = self.longest_from(first.endpoints.1, metric)?.expect("Graph contains the furthest node");
+
+        Ok(Some(if first.length > second.length { first } else { second }))
+    }
+
+    /// The shortest path from source to the node furthest from it
+    fn longest_from(&self, source : Id, metric : Metric) -> Result<Option<Diameter<Id, Cost>>, NegativeCostError<Id, Cost>> {
+        if !self.nodes.contains_key(&source) { return Ok(None); }
+
+        let (distances, predecessors) = self.reach(source, metric)?;
+        let (far, length) = Self::furthest_reached(&distances);
+
+        let mut nodes = vec![far];
+        while let Some(&previous) = predecessors.get(&nodes[nodes.len() - 1]) {
+            nodes.push(previous);
+        }
+        nodes.reverse();
+
+        let path = Path::along(self, &nodes).expect("Every hop of a path is a Go edge");
+        Ok(Some(Diameter { length, endpoints: (source, far), path }))
+    }
+
+    /// The eccentricity given the distances out of a node
+    fn furthest_of(&self, distances : &IntMap<Id, Length<Cost>>) -> Length<Cost> {
+        match distances.len() < self.nodes.len() {
+            true => Length::Infinite,
+            false => Self::furthest_reached(distances).1
+        }
+    }
+
+    /// The reached node furthest away, and its distance
+    fn furthest_reached(distances : &IntMap<Id, Length<Cost>>) -> (Id, Length<Cost>) {
+        distances
+            .iter()
+            .map(|(&id, &length)| (id, length))
+            .reduce(|a, b| if b.1 > a.1 { b } else { a })
+            .expect("Source reaches itself")
+    }
+
+    fn reach(&self, source : Id, metric : Metric) -> Result<Reach<Id, Cost>, NegativeCostError<Id, Cost>> {
+        match metric {
+            Metric::Hops => Ok(self.reach_hops(source)),
+            Metric::Cost => {
+                let paths = self.dijkstra(source)?;
+                let distances = paths.distances.iter().map(|(&id, &d)| (id, Length::Cost(d))).collect();
+                let predecessors = paths.predecessors.iter().map(|(&id, &(previous, _))| (id, previous)).collect();
+                Ok((distances, predecessors))
+            }
+        }
+    }
+
+    fn reach_hops(&self, source : Id) -> Reach<Id, Cost> {
+        let mut distances = IntMap::<Id, Length<Cost>>::default();
+        let mut predecessors = IntMap::<Id, Id>::default();
+
+        let mut queue = VecDeque::from([(source, 0usize)]);
+        distances.insert(source, Length::Hops(0));
+
+        while let Some((current, depth)) = queue.pop_front() {
+            for &neighbour in self.nodes[&current].neighbours() {
+                if distances.contains_key(&neighbour) { continue; }
+
+                distances.insert(neighbour, Length::Hops(depth + 1));
+                predecessors.insert(neighbour, current);
+                queue.push_back((neighbour, depth + 1));
+            }
+        }
+
+        (distances, predecessors)
+    }
+}
@@ -2,19 +2,33 @@
 use nohash_hasher::IntSet;
 use crate::types::{Identity, Scalar};
 use crate::{edge::Edge, node::Node};
-use std::collections::HashMap;
-use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+use std::collections::hash_map::{DefaultHasher, Entry};
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// Versions are unique across every graph, so a version identifies one graph in one state
+static VERSIONS: AtomicU64 = AtomicU64::new(0);
+
+fn next_version() -> u64 {
+    VERSIONS.fetch_add(1, Ordering::Relaxed)
+}
 
 pub struct Graph<Id, Cost> 
 {
     pub nodes: HashMap<Id, Node<Id, Cost>>,
+    version: u64
 }
 
 // Constructors
 impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
 { 
     pub fn new() -> Graph<Id, Cost> {
-        Graph { nodes : HashMap::new() }
+        Graph::from_nodes(HashMap::new())
+    }
+
+    pub fn from_nodes(nodes: HashMap<Id, Node<Id, Cost>>) -> Graph<Id, Cost> {
+        Graph { nodes, version : next_version() }
     }
 
     pub fn from_sparse(nodes: Vec<Id>, edges: Vec<(Id,Id,Cost)>) -> Graph<Id, Cost> {
@@ -27,7 +41,7 @@ impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
             };
         }
 
-        let mut graph = Graph{nodes : map};
+        let mut graph = Graph::from_nodes(map);
 
         for (from, to, cost) in edges.into_iter() {
             graph.connect_nodes(from, to, cost);
@@ -41,7 +55,14 @@ impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
 impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
 { 
 
+    /// Marks the graph as changed. 
+    /// Changes made directly through nodes are not seen, and should be followed by a call to this.
+    pub fn touch(&mut self) {
+        self.version = next_version();
+    }
+
     fn add_to_node(&mut self, id : Id, edge : Edge<Id, Cost>) {
+            self.touch();
             match self.nodes.entry(id) {
                 Entry::Occupied(mut n) => n.get_mut().edges.push(edge),
                 Entry::Vacant(_) => panic!("Attempted to connect a non-existant node - edge holds an incorrect id")
@@ -72,6 +93,7 @@ impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
     /// Does nothing if the connection does not exits.
     /// Panics if the nodes do not exist.
     pub fn disconnect_nodes(&mut self, a : Id, b : Id) {
+        self.touch();
 
         self.nodes
             .get_mut(&a)
@@ -86,6 +108,7 @@ impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
 
 
     pub fn destroy_node(&mut self, id : Id) {
+        self.touch();
         if let Some(connected_nodes) = self.nodes
             .get(&id)
             .and_then(|node| Some(node.pseudo_neighbours().cloned().collect::<Vec<_>>())) {
@@ -104,50 +127,145 @@ impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
 
     /// Shorthand for inserting a node
     pub fn insert_node(&mut self, id : Id) -> Option<Node<Id, Cost>> {
+        self.touch();
         self.nodes.insert(id, Node::<Id, Cost>::new())
     }
 
-    /// Removes node at id, and then continues to prune away neighbours that return true on the predicate.
-    /// If no nodes at id, returns.
-    pub fn prune_nodes<'a>(&'a mut self, id : Id, predicate : fn(id: Id, Node<Id, Cost>) -> bool) 
+    /// Removes node at id if it returns true on the predicate, and then continues to prune away 
+    /// neighbours that return true on the predicate, breadth first.
+    /// The predicate sees each node as it stands, so already pruned neighbours are gone.
+    /// Returns the removed ids in the order they were removed. If no nodes at id, returns empty.
+    pub fn prune_nodes<PREDICATE>(&mut self, id : Id, predicate : PREDICATE) -> Vec<Id>
+    where PREDICATE : FnMut(Id, &Node<Id, Cost>) -> bool
+    {
+        self.prune_from(id, predicate, false)
+    }
+
+    /// As prune_nodes, but spreading depth first.
+    pub fn df_prune_nodes<PREDICATE>(&mut self, id : Id, predicate : PREDICATE) -> Vec<Id>
+    where PREDICATE : FnMut(Id, &Node<Id, Cost>) -> bool
     {
-        // The pseudo state of the graph
-        // Contains ids of nodes to removed
+        self.prune_from(id, predicate, true)
+    }
+
+    fn prune_from<PREDICATE>(&mut self, id : Id, mut predicate : PREDICATE, depth_first : bool) -> Vec<Id>
+    where PREDICATE : FnMut(Id, &Node<Id, Cost>) -> bool
+    {
+        let mut removed = Vec::new();
         let mut set = IntSet::<Id>::default();
 
-        // Function first creates a pseudo node (a node with flagged nodes removed), then
-        // forwards the pseudo node to the predicate.
-        // The predicate decides both nodes to remove and the search direction.
-        let func = |id : Id| { 
-            
-            let pseudo_node = Node::from_edges(
-                self.nodes[&id]
-                    .edges
-                    .iter()
-                    .filter(|&edge| { 
-                        match edge {
-                            | Edge::Go { to, .. } => !set.contains(to),
-                            | Edge::NoGo { to } => !set.contains(to)
-            }}).cloned().collect());
-            
-            if predicate(id, pseudo_node)
-            {
-                set.insert(id);
-                true   
-            } else {
-                false
+        // Nodes are destroyed as soon as the predicate accepts them, leaving their neighbours
+        // queued so the pruning can spread from them later
+        let mut frontier = VecDeque::<Id>::from([id]);
+
+        while let Some(id) = if depth_first { frontier.pop_back() } else { frontier.pop_front() } {
+            if !set.insert(id) { continue; }
+
+            let Some(node) = self.nodes.get(&id) else { continue };
+            if predicate(id, node) {
+                // Stacked in reverse so the first neighbour is explored first
+                let neighbours = node.neighbours().cloned();
+                match depth_first {
+                    true => frontier.extend(neighbours.collect::<Vec<_>>().into_iter().rev()),
+                    false => frontier.extend(neighbours)
+                }
+
+                self.destroy_node(id);
+                removed.push(id);
             }
-        
-         };
+        }
 
-        let v : Vec<_> = self.bf_search(id, func, false).collect();
+        removed
+    }
+
+    /// Repeatedly removes every node in the graph that returns true on the predicate, until none do.
+    /// Only the neighbours of removed nodes are rechecked, rather than the whole graph each round.
+    /// Returns the removed ids in the order they were removed.
+    pub fn prune_until_stable<PREDICATE>(&mut self, mut predicate : PREDICATE) -> Vec<Id>
+    where PREDICATE : FnMut(Id, &Node<Id, Cost>) -> bool
+    {
+        let mut removed = Vec::new();
 
-        for i in v {
-            self.destroy_node(i);
+        let mut worklist : VecDeque<Id> = self.nodes.keys().cloned().collect();
+        let mut queued : IntSet<Id> = worklist.iter().cloned().collect();
+
+        while let Some(id) = worklist.pop_front() {
+            queued.remove(&id);
+
+            let Some(node) = self.nodes.get(&id) else { continue };
+            if !predicate(id, node) { continue; }
+
+            // Anything connected in either direction has changed
+            let connected : Vec<Id> = node.pseudo_neighbours().cloned().collect();
+            self.destroy_node(id);
+            removed.push(id);
+
+            for neighbour in connected {
+                if queued.insert(neighbour) {
+                    worklist.push_back(neighbour);
+                }
+            }
         }
-        
-        ()
 
+        removed
+    }
+}
+
+// Queries
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{
+    /// Identifies this graph in its current state - changes whenever the graph is mutated
+    /// through its methods. Changes made directly through nodes are not seen.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// A hash of every node and edge, independent of their order. Unlike version, this sees
+    /// changes made directly through nodes, but costs a pass over the whole graph.
+    pub fn fingerprint(&self) -> u64 {
+        let hash = |value : &dyn Fn(&mut DefaultHasher)| {
+            let mut hasher = DefaultHasher::new();
+            value(&mut hasher);
+            hasher.finish()
+        };
+
+        // Summing the hashes makes the order nodes and edges are visited in irrelevant
+        self.nodes.iter().fold(0u64, |total, (id, node)| {
+            node.edges.iter().fold(total.wrapping_add(hash(&|h| id.hash(h))), |total, edge| {
+                total.wrapping_add(hash(&|h| match edge {
+                    Edge::Go { to, cost } => (id, 0u8, to, cost.to_bits()).hash(h),
+                    Edge::NoGo { to } => (id, 1u8, to).hash(h)
+                }))
+            })
+        })
+    }
+
+    /// Returns the ids of every node holding a Go edge into id - the neighbours of id
+    /// when the graph is walked backwards. Returns empty if graph does not contain id.
+    pub fn predecessors(&self, id : Id) -> Vec<Id> {
+        let mut set = IntSet::<Id>::default();
+
+        self.nodes
+            .get(&id)
+            .map(|node| node
+                .pseudo_neighbours()
+                .filter(|&from| set.insert(*from))
+                .filter(|&from| self.nodes
+                    .get(from)
+                    .is_some_and(|n| n.edges.iter().any(|edge| edge.connects(&id))))
+                .cloned()
+                .collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the cost of the cheapest Go edge from one node to another, if there is one.
+    pub fn cost_between(&self, from : Id, to : Id) -> Option<Cost> {
+        self.nodes
+            .get(&from)?
+            .weighted_neighbours()
+            .filter(|&(id, _)| *id == to)
+            .map(|(_, &cost)| cost)
+            .reduce(|a, b| if b < a { b } else { a })
     }
 }
 
@@ -163,6 +281,7 @@ impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
     }
 
     pub fn iter_mut(& mut self) -> std::collections::hash_map::IterMut<'_, Id, Node<Id, Cost>> {
+        self.touch();
         self.nodes.iter_mut()
     }
 }
@@ -2,8 +2,20 @@ pub mod node;
 pub mod graph;
 pub mod edge;
 pub mod types;
+pub mod path;
 pub mod breadth_first_iter;
 pub mod depth_first_iter;
+pub mod walker;
+pub mod traversal_context;
+pub mod subgraph;
+pub mod random_walk;
+pub mod shortest_path;
+pub mod path_search;
+pub mod topological;
+pub mod contraction_hierarchy;
+pub mod distance_measures;
+
+mod scored;
 
 #[cfg(test)]
 pub mod tests;
@@ -35,6 +35,14 @@ impl<Id,Cost> Node<Id,Cost> where Id : Identity,
         })
     }
 
+    /// Return all possible neighbours this node is connected to, along with the cost of getting there
+    pub fn weighted_neighbours(&self) -> impl Iterator<Item = (&Id, &Cost)> {
+        self.edges.iter().filter_map(|edge| match edge {
+            Edge::Go { to, cost } => Some((to, cost)),
+            Edge::NoGo { .. } => None
+        })
+    }
+
     /// Return all possible neighbours this is node is connected to or are connect to this node
     pub fn pseudo_neighbours(&self) -> impl Iterator<Item = &Id> {
         self.edges.iter().map(|edge| match edge {
@@ -0,0 +1,196 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+
+use std::fmt;
+
+/*
+    Path
+
+    A route through a graph: the nodes visited in order and the cost paid on
+    each hop between them. A path always holds at least one node. Hop costs are
+    whatever the search that produced the path paid, so they may include more
+    than edge costs - use along to take them from the graph instead.
+
+*/
+
+/// A route through the nodes in order, with the cost of each hop between consecutive nodes
+#[derive(PartialEq, Clone, Debug)]
+pub struct Path<Id, Cost>
+{
+    nodes: Vec<Id>,
+    costs: Vec<Cost>
+}
+
+impl<Id, Cost> Path<Id, Cost> where Id : Identity, Cost : Scalar
+{
+    /// A path that starts, and so far ends, at start
+    pub fn new(start : Id) -> Path<Id, Cost> {
+        Path { nodes: vec![start], costs: Vec::new() }
+    }
+
+    /// The path through nodes taking the cheapest Go edge of each hop.
+    /// Errors if nodes is empty or a hop is not a Go edge of graph.
+    pub fn along(graph : &Graph<Id, Cost>, nodes : &[Id]) -> Result<Path<Id, Cost>, PathError<Id>> {
+        let (&start, rest) = nodes.split_first().ok_or(PathError::Empty)?;
+        let mut path = Path::new(start);
+
+        for &to in rest {
+            let from = path.end();
+            path.push(to, graph.cost_between(from, to).ok_or(PathError::MissingEdge { from, to })?);
+        }
+
+        Ok(path)
+    }
+
+    /// Extends the path to id at the given hop cost
+    pub fn push(&mut self, id : Id, cost : Cost) {
+        self.nodes.push(id);
+        self.costs.push(cost);
+    }
+
+    /// The nodes visited, in order
+    pub fn nodes(&self) -> &[Id] {
+        &self.nodes
+    }
+
+    /// The cost of each hop; one shorter than nodes
+    pub fn costs(&self) -> &[Cost] {
+        &self.costs
+    }
+
+    /// Each hop as (from, to, cost)
+    pub fn hops(&self) -> impl Iterator<Item=(Id, Id, Cost)> + '_ {
+        self.nodes.windows(2).zip(self.costs.iter()).map(|(hop, &cost)| (hop[0], hop[1], cost))
+    }
+
+    pub fn start(&self) -> Id {
+        self.nodes[0]
+    }
+
+    pub fn end(&self) -> Id {
+        self.nodes[self.nodes.len() - 1]
+    }
+
+    /// The number of hops
+    pub fn len(&self) -> usize {
+        self.costs.len()
+    }
+
+    /// Whether the path has no hops, only its start
+    pub fn is_empty(&self) -> bool {
+        self.costs.is_empty()
+    }
+
+    /// The sum of the hop costs
+    pub fn total_cost(&self) -> Cost {
+        self.costs.iter().fold(Cost::default(), |total, &cost| total + cost)
+    }
+
+    /// Whether the path returns to where it started
+    pub fn is_cyclic(&self) -> bool {
+        !self.is_empty() && self.start() == self.end()
+    }
+
+    /// Checks every hop is a Go edge of graph
+    pub fn validate(&self, graph : &Graph<Id, Cost>) -> Result<(), PathError<Id>> {
+        match self.hops().find(|&(from, to, _)| graph.cost_between(from, to).is_none()) {
+            Some((from, to, _)) => Err(PathError::MissingEdge { from, to }),
+            None => Ok(())
+        }
+    }
+
+    /// Reverses the direction of travel. Hop costs are kept, so on one way edges the
+    /// reversed path may not be valid.
+    pub fn reverse(&mut self) {
+        self.nodes.reverse();
+        self.costs.reverse();
+    }
+
+    /// Starts and ends a cyclic path at id instead, keeping its order.
+    /// Errors if the path is not cyclic or does not visit id.
+    pub fn rotate_to(&mut self, id : Id) -> Result<(), PathError<Id>> {
+        if !self.is_cyclic() { return Err(PathError::NotCyclic); }
+
+        // The closing node repeats the start, so is left out of the rotation
+        let k = self.nodes[..self.len()].iter().position(|&n| n == id).ok_or(PathError::NotOnPath(id))?;
+        self.nodes.pop();
+        self.nodes.rotate_left(k);
+        self.nodes.push(id);
+        self.costs.rotate_left(k);
+
+        Ok(())
+    }
+
+    /// Appends other, which must start where this path ends
+    pub fn concat(&mut self, other : Path<Id, Cost>) -> Result<(), PathError<Id>> {
+        if other.start() != self.end() {
+            return Err(PathError::Disjoint { end: self.end(), start: other.start() });
+        }
+
+        self.nodes.extend(other.nodes.into_iter().skip(1));
+        self.costs.extend(other.costs);
+
+        Ok(())
+    }
+
+    /// Splits the path at the first visit to id, both parts including it.
+    /// Returns None if the path does not visit id.
+    pub fn split_at(&self, id : Id) -> Option<(Path<Id, Cost>, Path<Id, Cost>)> {
+        let k = self.nodes.iter().position(|&n| n == id)?;
+
+        Some((
+            Path { nodes: self.nodes[..=k].to_vec(), costs: self.costs[..k].to_vec() },
+            Path { nodes: self.nodes[k..].to_vec(), costs: self.costs[k..].to_vec() }
+        ))
+    }
+
+    /// The pieces left after removing every hop also travelled by other, in either direction
+    pub fn subtract(&self, other : &Path<Id, Cost>) -> Vec<Path<Id, Cost>> {
+        let shared = |from : Id, to : Id| other.nodes.windows(2).any(|hop|
+            (hop[0] == from && hop[1] == to) || (hop[0] == to && hop[1] == from));
+
+        let mut pieces = Vec::new();
+        let mut piece = Path::new(self.start());
+
+        for (from, to, cost) in self.hops() {
+            if shared(from, to) {
+                if !piece.is_empty() { pieces.push(piece); }
+                piece = Path::new(to);
+            }
+            else {
+                piece.push(to, cost);
+            }
+        }
+        if !piece.is_empty() { pieces.push(piece); }
+
+        pieces
+    }
+}
+
+/// Returned when a path can not be built or changed as asked
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum PathError<Id> {
+    /// A path needs at least one node
+    Empty,
+    /// A hop with no Go edge between its nodes
+    MissingEdge { from: Id, to: Id },
+    /// Only a cyclic path can be rotated
+    NotCyclic,
+    NotOnPath(Id),
+    /// Paths can only be joined end to start
+    Disjoint { end: Id, start: Id }
+}
+
+impl<Id : fmt::Debug> fmt::Display for PathError<Id> {
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathError::Empty => write!(f, "a path needs at least one node"),
+            PathError::MissingEdge { from, to } => write!(f, "no Go edge {:?} -> {:?}", from, to),
+            PathError::NotCyclic => write!(f, "path is not cyclic"),
+            PathError::NotOnPath(id) => write!(f, "node {:?} is not on the path", id),
+            PathError::Disjoint { end, start } => write!(f, "path ending at {:?} can not be joined to one starting at {:?}", end, start)
+        }
+    }
+}
+
+impl<Id : fmt::Debug> std::error::Error for PathError<Id> {}
@@ -0,0 +1,103 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+use crate::path::Path;
+
+use nohash_hasher::IntSet;
+
+/*
+    Simple path enumeration
+
+    A backtracking search for routes out of a source node, never visiting a
+    node twice within one route. Each time the route is extended the SUCCESS 
+    closure decides whether it is complete, and the FAILURE closure whether the
+    branch is a dead end - both see the node just reached and the route so far.
+
+*/
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{ 
+    /// Returns an iterator over every simple path leaving id along Go edges that satisfies 
+    /// success, with the cost of the cheapest Go edge on each hop. Successful paths are not extended further, and paths 
+    /// satisfying failure are abandoned. The closures receive the node just reached and the path
+    /// ending at it. The source on its own is never tested.
+    /// Will return an empty iterator if graph does not contain node of id
+    pub fn simple_paths<SUCCESS, FAILURE>(&self, id : Id, success_fn : SUCCESS, failure_fn : FAILURE) -> SimplePaths<'_, Id, Cost, SUCCESS, FAILURE> 
+    where SUCCESS : FnMut(Id, &[Id]) -> bool, FAILURE : FnMut(Id, &[Id]) -> bool {
+        SimplePaths::<Id, Cost, SUCCESS, FAILURE>::new(id, self, success_fn, failure_fn)
+    }
+}
+
+pub struct SimplePaths<'a, Id, Cost, SUCCESS, FAILURE> where Id : Identity
+{
+    graph: &'a Graph<Id, Cost>,
+    path: Vec<Id>,
+    set: IntSet<Id>,
+    // The untried options at each step of the path
+    options: Vec<Vec<Id>>,
+    success_fn: SUCCESS,
+    failure_fn: FAILURE
+}
+
+impl<'a, Id, Cost, SUCCESS, FAILURE> SimplePaths<'a, Id, Cost, SUCCESS, FAILURE> 
+where Id : Identity, SUCCESS : FnMut(Id, &[Id]) -> bool, FAILURE : FnMut(Id, &[Id]) -> bool
+{
+    pub fn new(id : Id, graph : &'a Graph<Id, Cost>, success_fn : SUCCESS, failure_fn : FAILURE) -> SimplePaths<'a, Id, Cost, SUCCESS, FAILURE> {
+        let mut search = SimplePaths { graph, path: Vec::new(), set: IntSet::default(), options: Vec::new(), success_fn, failure_fn };
+
+        if graph.nodes.contains_key(&id) {
+            search.advance(id);
+        }
+
+        search
+    }
+
+    fn advance(&mut self, id : Id) {
+        self.set.insert(id);
+        self.path.push(id);
+
+        // Reversed so the first edge is tried first
+        let mut options : Vec<Id> = self.graph.nodes[&id].neighbours().cloned().collect();
+        options.reverse();
+        self.options.push(options);
+    }
+
+    fn retreat(&mut self) {
+        if let Some(id) = self.path.pop() {
+            self.set.remove(&id);
+        }
+        self.options.pop();
+    }
+}
+
+impl<'a, Id, Cost, SUCCESS, FAILURE> Iterator for SimplePaths<'a, Id, Cost, SUCCESS, FAILURE> 
+where Id : Identity, Cost : Scalar, SUCCESS : FnMut(Id, &[Id]) -> bool, FAILURE : FnMut(Id, &[Id]) -> bool
+{
+    type Item = Path<Id, Cost>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+
+        loop {
+            let option = match self.options.last_mut()?.pop() {
+                Some(id) => id,
+                None => { self.retreat(); continue; }
+            };
+
+            if self.set.contains(&option) { continue; }
+
+            self.path.push(option);
+
+            if (self.success_fn)(option, &self.path) {
+                let found = Path::along(self.graph, &self.path).expect("Every hop was taken along a Go edge");
+                self.path.pop();
+                return Some(found);
+            }
+
+            let failed = (self.failure_fn)(option, &self.path);
+            self.path.pop();
+
+            if !failed {
+                self.advance(option);
+            }
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/*
+    Random walk 
+
+    A seeded walk that steps from node to node along Go edges. Each step may 
+    instead restart at the source (as in personalised PageRank), and a walk 
+    that reaches a node with no way out ends - unless it is able to restart.
+
+*/
+
+/// How the next edge of a random walk is chosen
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum StepWeighting {
+    /// Every edge is equally likely
+    Uniform,
+    /// Edges are chosen in proportion to their cost
+    Cost,
+    /// Edges are chosen in proportion to the inverse of their cost
+    InverseCost
+}
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{ 
+    /// Returns an endless uniform random walk starting at (and first yielding) id, reproducible from seed.
+    /// Will return an empty iterator if graph does not contain node of id
+    pub fn random_walk(&self, id : Id, seed : u64) -> RandomWalkIter<'_, Id, Cost> {
+        RandomWalkIter::<Id, Cost>::new(id, self, seed)
+    }
+}
+
+pub struct RandomWalkIter<'a, Id, Cost> where Id : Identity
+{
+    graph: &'a Graph<Id, Cost>,
+    source: Id,
+    current: Option<Id>,
+    rng: StdRng,
+    weighting: StepWeighting,
+    restart: f64,
+    max_length: Option<usize>,
+    steps: usize,
+    started: bool
+}
+
+impl<'a, Id, Cost> RandomWalkIter<'a, Id, Cost> where Id : Identity, Cost : Scalar
+{
+    pub fn new(id : Id, graph : &'a Graph<Id, Cost>, seed : u64) -> RandomWalkIter<'a, Id, Cost> {
+        RandomWalkIter { 
+            graph, 
+            source: id, 
+            current: graph.nodes.contains_key(&id).then_some(id), 
+            rng: StdRng::seed_from_u64(seed), 
+            weighting: StepWeighting::Uniform, 
+            restart: 0.0, 
+            max_length: None, 
+            steps: 0, 
+            started: false 
+        }
+    }
+
+    /// Sets how the next edge is chosen
+    pub fn weighting(mut self, weighting : StepWeighting) -> RandomWalkIter<'a, Id, Cost> {
+        self.weighting = weighting;
+        self
+    }
+
+    /// Sets the probability of jumping back to the source instead of taking a step.
+    /// Panics if probability is not within [0, 1]
+    pub fn restart_probability(mut self, probability : f64) -> RandomWalkIter<'a, Id, Cost> {
+        assert!((0.0..=1.0).contains(&probability), "Restart probability must be within [0, 1]");
+        self.restart = probability;
+        self
+    }
+
+    /// Ends the walk after max_length steps (max_length + 1 nodes, counting the source)
+    pub fn max_length(mut self, max_length : usize) -> RandomWalkIter<'a, Id, Cost> {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    fn weight(&self, cost : Cost) -> f64 {
+        match self.weighting {
+            StepWeighting::Uniform => 1.0,
+            StepWeighting::Cost => cost.as_f64(),
+            StepWeighting::InverseCost => 1.0 / cost.as_f64()
+        }
+    }
+
+    fn step(&mut self, id : Id) -> Option<Id> {
+
+        if self.restart > 0.0 && self.rng.gen_bool(self.restart) {
+            return Some(self.source);
+        }
+
+        let mut options : Vec<(Id, f64)> = self.graph.nodes[&id]
+            .weighted_neighbours()
+            .map(|(&to, &cost)| (to, self.weight(cost)))
+            .collect();
+
+        if options.is_empty() {
+            // Dead end - only a restart can continue the walk
+            return (self.restart > 0.0).then_some(self.source);
+        }
+
+        // Zero cost edges weigh infinitely under InverseCost - they share the draw between them
+        if options.iter().any(|(_, weight)| *weight == f64::INFINITY) {
+            for (_, weight) in options.iter_mut() {
+                *weight = if *weight == f64::INFINITY { 1.0 } else { 0.0 };
+            }
+        }
+
+        // Falls back to uniform when the weights are unusable (all zero, negative, NaN, or too 
+        // large to sum)
+        let usable = options.iter().all(|(_, weight)| *weight >= 0.0)
+            && options.iter().map(|(_, weight)| weight).sum::<f64>().is_finite();
+
+        let index = match usable.then(|| WeightedIndex::new(options.iter().map(|(_, weight)| *weight))) {
+            Some(Ok(distribution)) => distribution.sample(&mut self.rng),
+            _ => self.rng.gen_range(0..options.len())
+        };
+
+        Some(options[index].0)
+    }
+}
+
+impl<'a, Id, Cost> Iterator for RandomWalkIter<'a, Id, Cost> where Id : Identity, Cost : Scalar
+{
+    type Item = Id;
+
+    fn next(&mut self) -> Option<Self::Item> {
+
+        if !self.started {
+            self.started = true;
+            return self.current;
+        }
+
+        if self.max_length.is_some_and(|max| self.steps >= max) {
+            self.current = None;
+        }
+
+        self.current = self.current.and_then(|id| self.step(id));
+        self.steps += 1;
+
+        self.current
+    }
+}
@@ -0,0 +1,25 @@
+use std::cmp::Ordering;
+
+/// A heap entry ordered so that a BinaryHeap pops the smallest score first.
+/// Incomparable scores (NaN) are treated as equal.
+pub(crate) struct MinScored<Score, T>(pub Score, pub T);
+
+impl<Score : PartialOrd, T> PartialEq for MinScored<Score, T> {
+    fn eq(&self, other : &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<Score : PartialOrd, T> Eq for MinScored<Score, T> {}
+
+impl<Score : PartialOrd, T> PartialOrd for MinScored<Score, T> {
+    fn partial_cmp(&self, other : &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Score : PartialOrd, T> Ord for MinScored<Score, T> {
+    fn cmp(&self, other : &Self) -> Ordering {
+        other.0.partial_cmp(&self.0).unwrap_or(Ordering::Equal)
+    }
+}
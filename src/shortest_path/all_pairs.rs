@@ -0,0 +1,169 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+use crate::path::Path;
+use crate::shortest_path::bellman_ford::NegativeCycle;
+
+use nohash_hasher::IntMap;
+
+/*
+    All-pairs shortest paths
+
+    Shortest distances between every pair of nodes along Go edges, held as a 
+    dense matrix. Floyd-Warshall suits dense graphs; Johnson's algorithm suits 
+    sparse ones - it reweighs the edges with Bellman-Ford potentials so that 
+    Dijkstra can be run from every node, even with negative costs. Either way 
+    a negative cycle is an error.
+
+*/
+
+/// Shortest distances between every pair of nodes. Rows are sources and columns targets, both
+/// indexed through index. Predecessors allow any shortest path to be rebuilt.
+#[derive(Clone, Debug)]
+pub struct AllPairsShortestPaths<Id, Cost> where Id : Identity
+{
+    /// The id at each row and column
+    pub ids: Vec<Id>,
+    /// The row and column of each id
+    pub index: IntMap<Id, usize>,
+    /// Row major; None where the target can not be reached from the source
+    pub distances: Vec<Option<Cost>>,
+    /// Row major; the index of the node before the target on a shortest path from the source,
+    /// and the cost of the Go edge from it. Each row is a shortest path tree rooted at its source.
+    pub predecessors: Vec<Option<(usize, Cost)>>
+}
+
+impl<Id, Cost> AllPairsShortestPaths<Id, Cost> where Id : Identity, Cost : Scalar
+{
+    fn new(graph : &Graph<Id, Cost>) -> AllPairsShortestPaths<Id, Cost> {
+        let ids : Vec<Id> = graph.nodes.keys().cloned().collect();
+        let index = ids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+        let n = ids.len();
+
+        AllPairsShortestPaths { ids, index, distances: vec![None; n * n], predecessors: vec![None; n * n] }
+    }
+
+    /// The shortest distance from source to target, if target can be reached
+    pub fn distance(&self, source : Id, target : Id) -> Option<Cost> {
+        let (i, j) = (*self.index.get(&source)?, *self.index.get(&target)?);
+        self.distances[i * self.ids.len() + j]
+    }
+
+    /// The shortest path from source to target, if target can be reached, with the cost of the
+    /// Go edge taken on each hop.
+    pub fn path(&self, source : Id, target : Id) -> Option<Path<Id, Cost>> {
+        let (i, mut j) = (*self.index.get(&source)?, *self.index.get(&target)?);
+        let n = self.ids.len();
+        self.distances[i * n + j]?;
+
+        // Walk back through the source's own tree, so the walk always ends at the source
+        let mut hops = Vec::new();
+        while j != i {
+            let (previous, cost) = self.predecessors[i * n + j]?;
+            hops.push((j, cost));
+            j = previous;
+        }
+
+        let mut path = Path::new(source);
+        for (j, cost) in hops.into_iter().rev() {
+            path.push(self.ids[j], cost);
+        }
+
+        Some(path)
+    }
+
+    /// Follows predecessors in row i back from i until a node repeats, returning the loop
+    fn predecessor_cycle(&self, i : usize) -> Vec<Id> {
+        let n = self.ids.len();
+        let mut seen = IntMap::<usize, usize>::default();
+        let mut walk = Vec::new();
+        let mut current = i;
+
+        while !seen.contains_key(&current) {
+            seen.insert(current, walk.len());
+            walk.push(current);
+            current = self.predecessors[i * n + current].expect("Every node on the way is reached from i").0;
+        }
+
+        walk[seen[&current]..].iter().rev().map(|&j| self.ids[j]).collect()
+    }
+}
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{ 
+    /// Returns the shortest paths between every pair of nodes, by Floyd-Warshall. O(n^3).
+    pub fn floyd_warshall(&self) -> Result<AllPairsShortestPaths<Id, Cost>, NegativeCycle<Id>> {
+        let mut all = AllPairsShortestPaths::new(self);
+        let n = all.ids.len();
+        let zero = Cost::default();
+
+        for i in 0..n {
+            all.distances[i * n + i] = Some(zero);
+            all.predecessors[i * n + i] = Some((i, zero));
+        }
+
+        for (i, id) in all.ids.iter().enumerate() {
+            for (to, &cost) in self.nodes[id].weighted_neighbours() {
+                let j = all.index[to];
+                if all.distances[i * n + j].is_none_or(|d| cost < d) {
+                    all.distances[i * n + j] = Some(cost);
+                    all.predecessors[i * n + j] = Some((i, cost));
+                }
+            }
+        }
+
+        for k in 0..n {
+            for i in 0..n {
+                let Some(to_k) = all.distances[i * n + k] else { continue };
+
+                for j in 0..n {
+                    let Some(from_k) = all.distances[k * n + j] else { continue };
+
+                    let through = to_k + from_k;
+                    if all.distances[i * n + j].is_none_or(|d| through < d) {
+                        all.distances[i * n + j] = Some(through);
+                        all.predecessors[i * n + j] = all.predecessors[k * n + j];
+                    }
+                }
+            }
+
+            // Any node on a negative cycle can reach itself for less than nothing. Stop as soon 
+            // as one does, since each further pass around the cycle grows the distances and 
+            // integer costs would overflow.
+            if let Some(i) = (0..n).find(|&i| all.distances[i * n + i].is_some_and(|d| d < zero)) {
+                return Err(NegativeCycle { cycle: all.predecessor_cycle(i) });
+            }
+        }
+
+        Ok(all)
+    }
+
+    /// Returns the shortest paths between every pair of nodes, by Johnson's algorithm. 
+    /// O(nm log n), so preferable to Floyd-Warshall on sparse graphs.
+    pub fn johnson(&self) -> Result<AllPairsShortestPaths<Id, Cost>, NegativeCycle<Id>> {
+        let mut all = AllPairsShortestPaths::new(self);
+        let n = all.ids.len();
+        let zero = Cost::default();
+
+        // Distances from a virtual node with a zero cost edge to every node
+        let (potentials, _) = self.spfa(self.nodes.keys().cloned())?;
+
+        let reweigh = |from : Id, to : Id, cost : Cost| {
+            let reweighed = cost + potentials[&from] - potentials[&to];
+
+            // Never negative in exact arithmetic, but floats can round just below zero
+            Some(if reweighed < zero { zero } else { reweighed })
+        };
+
+        for (i, &source) in all.ids.iter().enumerate() {
+            let paths = self.dijkstra_by(source, None, reweigh).expect("Reweighed costs are non-negative");
+
+            for (&target, &distance) in paths.distances.iter() {
+                let j = all.index[&target];
+                all.distances[i * n + j] = Some(distance - potentials[&source] + potentials[&target]);
+                all.predecessors[i * n + j] = Some(paths.predecessors.get(&target).map_or((i, zero), |&(previous, cost)| (all.index[&previous], cost)));
+            }
+        }
+
+        Ok(all)
+    }
+}
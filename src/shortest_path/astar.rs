@@ -0,0 +1,86 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+use crate::path::Path;
+use crate::scored::MinScored;
+
+use nohash_hasher::IntMap;
+use std::collections::BinaryHeap;
+
+/*
+    A* search 
+
+    A goal-directed shortest path search along Go edges. The HEURISTIC closure 
+    estimates the remaining cost from a node to the nearest goal - the result 
+    is only guaranteed shortest when it never overestimates. Costs are assumed 
+    non-negative.
+
+*/
+
+/// Counters describing how much work a search did
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct SearchStats {
+    /// Nodes taken off the open set and expanded
+    pub expanded: usize,
+    /// Entries added to the open set
+    pub pushed: usize
+}
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{ 
+    /// Returns the path to the cheapest node satisfying goal_fn, or None if no such node can be reached.
+    pub fn astar<GOAL, HEURISTIC>(&self, start : Id, goal_fn : GOAL, heuristic : HEURISTIC) -> Option<Path<Id, Cost>> 
+    where GOAL : FnMut(Id) -> bool, HEURISTIC : FnMut(Id) -> Cost {
+        self.astar_with_stats(start, goal_fn, heuristic).0
+    }
+
+    /// As astar, also returning counters for comparing heuristics
+    pub fn astar_with_stats<GOAL, HEURISTIC>(&self, start : Id, mut goal_fn : GOAL, mut heuristic : HEURISTIC) -> (Option<Path<Id, Cost>>, SearchStats) 
+    where GOAL : FnMut(Id) -> bool, HEURISTIC : FnMut(Id) -> Cost {
+        let mut stats = SearchStats::default();
+        let mut costs = IntMap::<Id, Cost>::default();
+        let mut predecessors = IntMap::<Id, (Id, Cost)>::default();
+        let mut heap = BinaryHeap::new();
+
+        if self.nodes.contains_key(&start) {
+            costs.insert(start, Cost::default());
+            heap.push(MinScored(heuristic(start), (Cost::default(), start)));
+            stats.pushed += 1;
+        }
+
+        while let Some(MinScored(_, (cost, id))) = heap.pop() {
+
+            // A cheaper route here has been found since this entry was pushed
+            if costs[&id] < cost { continue; }
+
+            if goal_fn(id) {
+                let mut hops = Vec::new();
+                let mut current = id;
+                while let Some(&(previous, edge_cost)) = predecessors.get(&current) {
+                    hops.push((current, edge_cost));
+                    current = previous;
+                }
+
+                let mut path = Path::new(start);
+                for (id, edge_cost) in hops.into_iter().rev() {
+                    path.push(id, edge_cost);
+                }
+
+                return (Some(path), stats);
+            }
+
+            stats.expanded += 1;
+
+            for (&neighbour, &edge_cost) in self.nodes[&id].weighted_neighbours() {
+                let next = cost + edge_cost;
+                if costs.get(&neighbour).is_none_or(|&c| next < c) {
+                    costs.insert(neighbour, next);
+                    predecessors.insert(neighbour, (id, edge_cost));
+                    heap.push(MinScored(next + heuristic(neighbour), (next, neighbour)));
+                    stats.pushed += 1;
+                }
+            }
+        }
+
+        (None, stats)
+    }
+}
@@ -0,0 +1,115 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+use crate::shortest_path::shortest_paths::ShortestPaths;
+
+use nohash_hasher::{IntMap, IntSet};
+use std::collections::VecDeque;
+use std::fmt;
+
+/*
+    Bellman-Ford
+
+    Single-source shortest paths along Go edges that may carry negative costs, 
+    using the queue-based (SPFA) form - only nodes whose distance just improved
+    are relaxed again. A negative cycle reachable from the source is an error.
+
+    Note that connect_nodes makes a Go edge each way, so a single negative 
+    two way edge is already a negative cycle.
+
+*/
+
+/// Returned when a negative cycle is reachable from the source. Each node of the cycle has a 
+/// Go edge to the next, and the last has a Go edge back to the first.
+#[derive(PartialEq, Clone, Debug)]
+pub struct NegativeCycle<Id> {
+    pub cycle: Vec<Id>
+}
+
+impl<Id : fmt::Debug> fmt::Display for NegativeCycle<Id> {
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "graph contains a negative cycle: {:?}", self.cycle)
+    }
+}
+
+impl<Id : fmt::Debug> std::error::Error for NegativeCycle<Id> {}
+
+type DistancesAndPredecessors<Id, Cost> = (IntMap<Id, Cost>, IntMap<Id, (Id, Cost)>);
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{ 
+    /// Returns the shortest paths from source to every reachable node.
+    /// Returns empty paths if graph does not contain source.
+    pub fn bellman_ford(&self, source : Id) -> Result<ShortestPaths<Id, Cost>, NegativeCycle<Id>> {
+        let sources = self.nodes.contains_key(&source).then_some(source);
+        let (distances, predecessors) = self.spfa(sources)?;
+
+        Ok(ShortestPaths { source, distances, predecessors })
+    }
+
+    /// Shortest distances from whichever of the sources is nearest - as if from a virtual node
+    /// with a zero cost edge to each source
+    pub(crate) fn spfa<SOURCES>(&self, sources : SOURCES) -> Result<DistancesAndPredecessors<Id, Cost>, NegativeCycle<Id>> 
+    where SOURCES : IntoIterator<Item = Id> {
+        let mut distances = IntMap::<Id, Cost>::default();
+        let mut predecessors = IntMap::<Id, (Id, Cost)>::default();
+
+        // The number of edges on the current best path to each node
+        let mut lengths = IntMap::<Id, usize>::default();
+        let mut queue = VecDeque::new();
+        let mut queued = IntSet::<Id>::default();
+
+        for source in sources {
+            distances.insert(source, Cost::default());
+            lengths.insert(source, 0);
+            queue.push_back(source);
+            queued.insert(source);
+        }
+
+        while let Some(id) = queue.pop_front() {
+            queued.remove(&id);
+            let distance = distances[&id];
+
+            for (&neighbour, &cost) in self.nodes[&id].weighted_neighbours() {
+                let next = distance + cost;
+                if distances.get(&neighbour).is_some_and(|&d| next >= d) { continue; }
+
+                distances.insert(neighbour, next);
+                predecessors.insert(neighbour, (id, cost));
+
+                let length = lengths[&id] + 1;
+                lengths.insert(neighbour, length);
+
+                // A shortest path can not hold every node - some node repeats, so a cycle is 
+                // improving the distance. Once it shows up in the predecessors it can be reported.
+                if length >= self.nodes.len() {
+                    if let Some(cycle) = predecessor_cycle(&predecessors, neighbour) {
+                        return Err(NegativeCycle { cycle });
+                    }
+                }
+
+                if queued.insert(neighbour) {
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        Ok((distances, predecessors))
+    }
+}
+
+/// Follows predecessors back from id, returning the cycle (in forward order) if they loop
+fn predecessor_cycle<Id : Identity, Cost>(predecessors : &IntMap<Id, (Id, Cost)>, id : Id) -> Option<Vec<Id>> {
+    let mut seen = IntMap::<Id, usize>::default();
+    let mut walk = Vec::new();
+    let mut current = id;
+
+    while !seen.contains_key(&current) {
+        seen.insert(current, walk.len());
+        walk.push(current);
+        current = predecessors.get(&current)?.0;
+    }
+
+    let mut cycle = walk.split_off(seen[&current]);
+    cycle.reverse();
+    Some(cycle)
+}
@@ -0,0 +1,124 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+use crate::path::Path;
+use crate::scored::MinScored;
+use crate::shortest_path::shortest_paths::NegativeCostError;
+
+use nohash_hasher::{IntMap, IntSet};
+use std::collections::BinaryHeap;
+
+/*
+    Bidirectional Dijkstra
+
+    A point-to-point Dijkstra grown from both ends at once - forward from the 
+    source along Go edges, and backward from the target along the reverse of 
+    Go edges. Every edge relaxed next to the other search's territory gives a
+    candidate route; the search stops once the two smallest open distances add 
+    up to no less than the best candidate, at which point it is the shortest.
+
+*/
+
+struct Side<Id, Cost> where Id : Identity
+{
+    distances: IntMap<Id, Cost>,
+    // Towards the source going forwards, towards the target going backwards
+    parents: IntMap<Id, Id>,
+    settled: IntSet<Id>,
+    heap: BinaryHeap<MinScored<Cost, Id>>
+}
+
+impl<Id, Cost> Side<Id, Cost> where Id : Identity, Cost : Scalar
+{
+    fn new(id : Id) -> Side<Id, Cost> {
+        let mut side = Side { distances: IntMap::default(), parents: IntMap::default(), settled: IntSet::default(), heap: BinaryHeap::new() };
+        side.distances.insert(id, Cost::default());
+        side.heap.push(MinScored(Cost::default(), id));
+        side
+    }
+
+    /// The smallest open distance, after discarding stale entries
+    fn peek(&mut self) -> Option<Cost> {
+        while let Some(MinScored(distance, id)) = self.heap.peek() {
+            if !self.settled.contains(id) { return Some(*distance); }
+            self.heap.pop();
+        }
+        None
+    }
+}
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{ 
+    /// Returns the shortest path from source to target, or None if target can not be reached from source.
+    pub fn bidirectional_dijkstra(&self, source : Id, target : Id) -> Result<Option<Path<Id, Cost>>, NegativeCostError<Id, Cost>> {
+        if !self.nodes.contains_key(&source) || !self.nodes.contains_key(&target) {
+            return Ok(None);
+        }
+
+        let mut forward = Side::<Id, Cost>::new(source);
+        let mut backward = Side::<Id, Cost>::new(target);
+
+        // The best route found so far, and the node it passes through
+        let mut best : Option<(Cost, Id)> = (source == target).then_some((Cost::default(), source));
+
+        // Once either side runs dry, every route has been seen
+        while let (Some(f), Some(b)) = (forward.peek(), backward.peek()) {
+            if best.is_some_and(|(cost, _)| f + b >= cost) { break; }
+
+            let going_forward = f <= b;
+            let (this, other) = match going_forward {
+                true => (&mut forward, &backward),
+                false => (&mut backward, &forward)
+            };
+
+            let MinScored(distance, id) = this.heap.pop().expect("Just peeked");
+            this.settled.insert(id);
+
+            let edges : Vec<(Id, Cost)> = match going_forward {
+                true => self.nodes[&id].weighted_neighbours().map(|(&to, &cost)| (to, cost)).collect(),
+                false => self.predecessors(id)
+                    .into_iter()
+                    .map(|from| (from, self.cost_between(from, id).expect("A predecessor has a Go edge here")))
+                    .collect()
+            };
+
+            for (neighbour, cost) in edges {
+                if cost < Cost::default() {
+                    let (from, to) = if going_forward { (id, neighbour) } else { (neighbour, id) };
+                    return Err(NegativeCostError { from, to, cost });
+                }
+
+                let next = distance + cost;
+                if this.distances.get(&neighbour).is_none_or(|&d| next < d) {
+                    this.distances.insert(neighbour, next);
+                    this.parents.insert(neighbour, id);
+                    this.heap.push(MinScored(next, neighbour));
+                }
+
+                if let Some(&remaining) = other.distances.get(&neighbour) {
+                    let through = this.distances[&neighbour] + remaining;
+                    if best.is_none_or(|(cost, _)| through < cost) {
+                        best = Some((through, neighbour));
+                    }
+                }
+            }
+        }
+
+        let Some((_, meet)) = best else { return Ok(None) };
+
+        let mut path = vec![meet];
+        let mut current = meet;
+        while let Some(&parent) = forward.parents.get(&current) {
+            path.push(parent);
+            current = parent;
+        }
+        path.reverse();
+
+        let mut current = meet;
+        while let Some(&parent) = backward.parents.get(&current) {
+            path.push(parent);
+            current = parent;
+        }
+
+        Ok(Some(Path::along(self, &path).expect("Every hop of a path is a Go edge")))
+    }
+}
@@ -0,0 +1,108 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+use crate::path::Path;
+use crate::scored::MinScored;
+
+use nohash_hasher::{IntMap, IntSet};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/*
+    Bottleneck paths
+
+    Paths judged by their single worst edge rather than the sum of their costs. 
+    The widest path maximises the smallest cost along it - useful when costs are 
+    capacities or confidences. The minimax path minimises the largest cost along
+    it. Both follow Go edges and work with negative costs.
+
+*/
+
+/// A path value ordered so that the better bottleneck is the smaller
+#[derive(Clone, Copy, PartialEq)]
+struct Bottleneck<Cost> {
+    value: Cost,
+    widest: bool
+}
+
+impl<Cost : PartialOrd> PartialOrd for Bottleneck<Cost> {
+    fn partial_cmp(&self, other : &Self) -> Option<Ordering> {
+        match self.widest {
+            true => other.value.partial_cmp(&self.value),
+            false => self.value.partial_cmp(&other.value)
+        }
+    }
+}
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{ 
+    /// Returns the path from source to target whose smallest edge cost is largest, along with that cost.
+    /// A path from source to itself has no edges and is given the default cost.
+    /// Returns None if target cannot be reached.
+    pub fn widest_path(&self, source : Id, target : Id) -> Option<(Path<Id, Cost>, Cost)> {
+        self.bottleneck_path(source, target, true)
+    }
+
+    /// Returns the path from source to target whose largest edge cost is smallest, along with that cost.
+    /// A path from source to itself has no edges and is given the default cost.
+    /// Returns None if target cannot be reached.
+    pub fn minimax_path(&self, source : Id, target : Id) -> Option<(Path<Id, Cost>, Cost)> {
+        self.bottleneck_path(source, target, false)
+    }
+
+    fn bottleneck_path(&self, source : Id, target : Id, widest : bool) -> Option<(Path<Id, Cost>, Cost)> {
+        if !self.nodes.contains_key(&source) { return None; }
+        if source == target { return Some((Path::new(source), Cost::default())); }
+
+        let mut best = IntMap::<Id, Bottleneck<Cost>>::default();
+        let mut predecessors = IntMap::<Id, (Id, Cost)>::default();
+        let mut settled = IntSet::<Id>::default();
+        let mut heap = BinaryHeap::new();
+
+        settled.insert(source);
+        let mut frontier = Some((source, None));
+
+        while let Some((id, bottleneck)) = frontier {
+            if id == target { break; }
+
+            for (&neighbour, &cost) in self.nodes[&id].weighted_neighbours() {
+                if settled.contains(&neighbour) { continue; }
+
+                // The path's bottleneck is whichever is worse, the edge or the path so far
+                let edge = Bottleneck { value: cost, widest };
+                let next = match bottleneck {
+                    Some(bottleneck) if bottleneck > edge => bottleneck,
+                    _ => edge
+                };
+
+                if best.get(&neighbour).is_none_or(|&b| next < b) {
+                    best.insert(neighbour, next);
+                    predecessors.insert(neighbour, (id, cost));
+                    heap.push(MinScored(next, neighbour));
+                }
+            }
+
+            frontier = None;
+            while let Some(MinScored(bottleneck, id)) = heap.pop() {
+                if settled.insert(id) {
+                    frontier = Some((id, Some(bottleneck)));
+                    break;
+                }
+            }
+        }
+
+        let value = best.get(&target)?.value;
+        let mut hops = Vec::new();
+        let mut current = target;
+        while let Some(&(previous, cost)) = predecessors.get(&current) {
+            hops.push((current, cost));
+            current = previous;
+        }
+
+        let mut path = Path::new(source);
+        for (id, cost) in hops.into_iter().rev() {
+            path.push(id, cost);
+        }
+
+        Some((path, value))
+    }
+}
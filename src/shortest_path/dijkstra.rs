@@ -0,0 +1,73 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+use crate::scored::MinScored;
+use crate::shortest_path::shortest_paths::{NegativeCostError, ShortestPaths};
+
+use nohash_hasher::{IntMap, IntSet};
+use std::collections::BinaryHeap;
+
+/*
+    Dijkstra
+
+    Single-source shortest paths along Go edges, using their costs. Costs must 
+    be non-negative - a negative edge met during the search is an error. Edges
+    the search never reaches are not checked.
+
+*/
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{ 
+    /// Returns the shortest paths from source to every reachable node.
+    /// Returns empty paths if graph does not contain source.
+    pub fn dijkstra(&self, source : Id) -> Result<ShortestPaths<Id, Cost>, NegativeCostError<Id, Cost>> {
+        self.dijkstra_by(source, None, |_, _, cost| Some(cost))
+    }
+
+    /// Returns the shortest paths from source, stopping as soon as target is settled. 
+    /// Only target and the nodes nearer than it are kept.
+    pub fn dijkstra_to(&self, source : Id, target : Id) -> Result<ShortestPaths<Id, Cost>, NegativeCostError<Id, Cost>> {
+        self.dijkstra_by(source, Some(target), |_, _, cost| Some(cost))
+    }
+
+    /// Dijkstra where each edge (from, to, cost) is weighed by the weight closure instead of its cost.
+    /// Edges weighed None are not travelled.
+    pub(crate) fn dijkstra_by<WEIGHT>(&self, source : Id, target : Option<Id>, mut weight : WEIGHT) -> Result<ShortestPaths<Id, Cost>, NegativeCostError<Id, Cost>> 
+    where WEIGHT : FnMut(Id, Id, Cost) -> Option<Cost> {
+        let mut distances = IntMap::<Id, Cost>::default();
+        let mut predecessors = IntMap::<Id, (Id, Cost)>::default();
+        let mut settled = IntSet::<Id>::default();
+        let mut heap = BinaryHeap::new();
+
+        if self.nodes.contains_key(&source) {
+            distances.insert(source, Cost::default());
+            heap.push(MinScored(Cost::default(), source));
+        }
+
+        while let Some(MinScored(distance, id)) = heap.pop() {
+            if !settled.insert(id) { continue; }
+            if target == Some(id) {
+                // Anything unsettled only holds a tentative distance
+                distances.retain(|id, _| settled.contains(id));
+                predecessors.retain(|id, _| settled.contains(id));
+                break;
+            }
+
+            for (&neighbour, &cost) in self.nodes[&id].weighted_neighbours() {
+                let Some(weighed) = weight(id, neighbour, cost) else { continue };
+                if weighed < Cost::default() {
+                    return Err(NegativeCostError { from: id, to: neighbour, cost: weighed });
+                }
+
+                // Paths keep the edge's own cost, whatever it was weighed as
+                let next = distance + weighed;
+                if distances.get(&neighbour).is_none_or(|&d| next < d) {
+                    distances.insert(neighbour, next);
+                    predecessors.insert(neighbour, (id, cost));
+                    heap.push(MinScored(next, neighbour));
+                }
+            }
+        }
+
+        Ok(ShortestPaths { source, distances, predecessors })
+    }
+}
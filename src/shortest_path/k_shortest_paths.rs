@@ -0,0 +1,84 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+use crate::path::Path;
+use crate::scored::MinScored;
+use crate::shortest_path::shortest_paths::NegativeCostError;
+
+use nohash_hasher::IntSet;
+use std::collections::{BinaryHeap, HashSet};
+
+/*
+    k shortest loopless paths (Yen's algorithm)
+
+    Ranks the simple paths between two nodes by total Go edge cost. Each new 
+    path branches off an earlier one at a spur node: the earlier path is kept 
+    up to the spur, then a shortest path is found from the spur that avoids 
+    the root nodes and every edge already taken from that root. Costs must be 
+    non-negative.
+
+*/
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{ 
+    /// Returns up to k distinct simple paths from source to target, cheapest first.
+    pub fn k_shortest_paths(&self, source : Id, target : Id, k : usize) -> Result<Vec<Path<Id, Cost>>, NegativeCostError<Id, Cost>> {
+        let mut found = Vec::<Path<Id, Cost>>::new();
+        if k == 0 { return Ok(found); }
+
+        match self.dijkstra_to(source, target)?.path_to(target) {
+            Some(path) => found.push(self.along(path.nodes())),
+            None => return Ok(found)
+        }
+
+        let mut candidates = BinaryHeap::<MinScored<Cost, Path<Id, Cost>>>::new();
+        let mut seen = HashSet::<Vec<Id>>::new();
+        seen.insert(found[0].nodes().to_vec());
+
+        while found.len() < k {
+            let previous = found[found.len() - 1].nodes().to_vec();
+
+            for i in 0..previous.len() - 1 {
+                let spur = previous[i];
+                let root = &previous[..=i];
+
+                // Edges already taken from this root, and the root itself, are off limits
+                let banned_edges : HashSet<(Id, Id)> = found
+                    .iter()
+                    .map(|path| path.nodes())
+                    .filter(|path| path.len() > i + 1 && &path[..=i] == root)
+                    .map(|path| (path[i], path[i + 1]))
+                    .collect();
+                let banned_nodes : IntSet<Id> = root[..i].iter().cloned().collect();
+
+                let spur_paths = self.dijkstra_by(spur, Some(target), |from, to, cost| {
+                    match banned_nodes.contains(&to) || banned_edges.contains(&(from, to)) {
+                        true => None,
+                        false => Some(cost)
+                    }
+                })?;
+
+                let Some(spur_path) = spur_paths.path_to(target) else { continue };
+
+                let mut nodes = root[..i].to_vec();
+                nodes.extend_from_slice(spur_path.nodes());
+
+                if seen.insert(nodes.clone()) {
+                    let path = self.along(&nodes);
+                    candidates.push(MinScored(path.total_cost(), path));
+                }
+            }
+
+            match candidates.pop() {
+                Some(MinScored(_, path)) => found.push(path),
+                None => break
+            }
+        }
+
+        Ok(found)
+    }
+
+    /// The path through nodes found by a search, along the cheapest Go edge of each hop
+    fn along(&self, nodes : &[Id]) -> Path<Id, Cost> {
+        Path::along(self, nodes).expect("Every hop of a path is a Go edge")
+    }
+}
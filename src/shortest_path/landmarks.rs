@@ -0,0 +1,229 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+use crate::path::Path;
+use crate::scored::MinScored;
+use crate::shortest_path::astar::SearchStats;
+use crate::shortest_path::shortest_paths::NegativeCostError;
+
+use nohash_hasher::IntMap;
+use rand::rngs::StdRng;
+use rand::seq::IteratorRandom;
+use rand::SeedableRng;
+use std::collections::BinaryHeap;
+use std::fmt::Display;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::str::FromStr;
+
+/*
+    ALT - A*, landmarks and the triangle inequality
+
+    Goal-directed search for graphs with no geometry to build a heuristic from.
+    Shortest distances to and from a handful of landmark nodes are computed up 
+    front; for any landmark L the triangle inequality bounds the remaining cost 
+    from v to the goal t from below by both d(L, t) - d(L, v) and d(v, L) - d(t, L).
+    The largest such bound is an admissible A* heuristic. Costs must be non-negative.
+
+*/
+
+const FILE_HEADER: &str = "grafic-landmarks 1";
+
+/// How landmarks are chosen
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum LandmarkSelection {
+    /// Each landmark is the node furthest from those already chosen
+    Farthest,
+    /// Landmarks are drawn at random, reproducibly from the seed
+    Random { seed: u64 }
+}
+
+/// Shortest distances to and from a set of landmark nodes. Only valid for the graph they were
+/// computed on, in the state they were computed in.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Landmarks<Id, Cost> where Id : Identity
+{
+    pub landmarks: Vec<Id>,
+    /// For each landmark, the distance from it to every node it reaches
+    pub from: Vec<IntMap<Id, Cost>>,
+    /// For each landmark, the distance to it from every node that reaches it
+    pub to: Vec<IntMap<Id, Cost>>
+}
+
+impl<Id, Cost> Landmarks<Id, Cost> where Id : Identity, Cost : Scalar
+{
+    /// A lower bound on the cost of travelling from id to goal
+    pub fn lower_bound(&self, id : Id, goal : Id) -> Cost {
+        let zero = Cost::default();
+
+        // Differences are only taken when positive, so unsigned costs never underflow
+        let gap = |a : Option<&Cost>, b : Option<&Cost>| match (a, b) {
+            (Some(&a), Some(&b)) if a > b => a - b,
+            _ => zero
+        };
+
+        (0..self.landmarks.len())
+            .flat_map(|i| [
+                gap(self.from[i].get(&goal), self.from[i].get(&id)),
+                gap(self.to[i].get(&id), self.to[i].get(&goal))
+            ])
+            .fold(zero, |best, bound| if bound > best { bound } else { best })
+    }
+
+    /// Writes the tables out as text
+    pub fn save<W : Write>(&self, writer : &mut W) -> io::Result<()> where Id : Display, Cost : Display {
+        writeln!(writer, "{}", FILE_HEADER)?;
+
+        for landmark in self.landmarks.iter() {
+            writeln!(writer, "landmark {}", landmark)?;
+        }
+        for (i, table) in self.from.iter().enumerate() {
+            for (id, cost) in table.iter() {
+                writeln!(writer, "from {} {} {}", i, id, cost)?;
+            }
+        }
+        for (i, table) in self.to.iter().enumerate() {
+            for (id, cost) in table.iter() {
+                writeln!(writer, "to {} {} {}", i, id, cost)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads tables written by save
+    pub fn load<R : Read>(reader : R) -> io::Result<Landmarks<Id, Cost>> where Id : FromStr, Cost : FromStr {
+        let invalid = |line : &str| io::Error::new(io::ErrorKind::InvalidData, format!("invalid landmark line: {}", line));
+
+        let mut lines = BufReader::new(reader).lines();
+        if lines.next().transpose()?.as_deref() != Some(FILE_HEADER) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "not a landmark file"));
+        }
+
+        let mut landmarks = Landmarks { landmarks: Vec::new(), from: Vec::new(), to: Vec::new() };
+
+        for line in lines {
+            let line = line?;
+            let fields : Vec<&str> = line.split_whitespace().collect();
+
+            match fields.as_slice() {
+                [] => (),
+                ["landmark", id] => {
+                    landmarks.landmarks.push(id.parse().map_err(|_| invalid(&line))?);
+                    landmarks.from.push(IntMap::default());
+                    landmarks.to.push(IntMap::default());
+                },
+                [direction @ ("from" | "to"), index, id, cost] => {
+                    let index : usize = index.parse().map_err(|_| invalid(&line))?;
+                    let table = match *direction {
+                        "from" => landmarks.from.get_mut(index),
+                        _ => landmarks.to.get_mut(index)
+                    }.ok_or_else(|| invalid(&line))?;
+
+                    table.insert(id.parse().map_err(|_| invalid(&line))?, cost.parse().map_err(|_| invalid(&line))?);
+                },
+                _ => return Err(invalid(&line))
+            }
+        }
+
+        Ok(landmarks)
+    }
+}
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{ 
+    /// Chooses up to count landmarks and computes their distance tables.
+    pub fn landmarks(&self, count : usize, selection : LandmarkSelection) -> Result<Landmarks<Id, Cost>, NegativeCostError<Id, Cost>> {
+        let mut landmarks = Landmarks { landmarks: Vec::new(), from: Vec::new(), to: Vec::new() };
+        let count = count.min(self.nodes.len());
+
+        let add = |landmarks : &mut Landmarks<Id, Cost>, id : Id| -> Result<(), NegativeCostError<Id, Cost>> {
+            landmarks.landmarks.push(id);
+            landmarks.from.push(self.dijkstra(id)?.distances);
+            landmarks.to.push(self.distances_to(id)?);
+            Ok(())
+        };
+
+        match selection {
+            LandmarkSelection::Random { seed } => {
+                let mut rng = StdRng::seed_from_u64(seed);
+                for id in self.nodes.keys().cloned().choose_multiple(&mut rng, count) {
+                    add(&mut landmarks, id)?;
+                }
+            },
+            LandmarkSelection::Farthest => {
+                // Start from whichever node lies furthest from an arbitrary one
+                let Some(&arbitrary) = self.nodes.keys().next() else { return Ok(landmarks) };
+                let mut next = self.furthest(&[self.dijkstra(arbitrary)?.distances], &[]);
+
+                while landmarks.landmarks.len() < count {
+                    add(&mut landmarks, next)?;
+                    if landmarks.landmarks.len() == count { break; }
+                    next = self.furthest(&landmarks.from, &landmarks.landmarks);
+                }
+            }
+        }
+
+        Ok(landmarks)
+    }
+
+    /// As astar towards goal, using the landmark lower bounds as the heuristic
+    pub fn alt(&self, landmarks : &Landmarks<Id, Cost>, start : Id, goal : Id) -> Option<Path<Id, Cost>> {
+        self.alt_with_stats(landmarks, start, goal).0
+    }
+
+    /// As alt, also returning counters for comparing landmark choices
+    pub fn alt_with_stats(&self, landmarks : &Landmarks<Id, Cost>, start : Id, goal : Id) -> (Option<Path<Id, Cost>>, SearchStats) {
+        self.astar_with_stats(start, |id| id == goal, |id| landmarks.lower_bound(id, goal))
+    }
+
+    /// The node not yet chosen that is furthest from its nearest table. Nodes missing from 
+    /// every table count as furthest of all, so unreached parts of the graph get a landmark.
+    fn furthest(&self, tables : &[IntMap<Id, Cost>], chosen : &[Id]) -> Id {
+        let nearest = |id : &Id| tables
+            .iter()
+            .map(|table| table.get(id).copied())
+            .reduce(|a, b| match (a, b) {
+                (Some(a), Some(b)) => Some(if b < a { b } else { a }),
+                (a, b) => a.or(b)
+            })
+            .flatten();
+
+        self.nodes
+            .keys()
+            .filter(|id| !chosen.contains(id))
+            .max_by(|a, b| match (nearest(a), nearest(b)) {
+                (None, None) => std::cmp::Ordering::Equal,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .cloned()
+            .expect("Graph is not empty")
+    }
+
+    /// Shortest distances to target from every node that can reach it
+    fn distances_to(&self, target : Id) -> Result<IntMap<Id, Cost>, NegativeCostError<Id, Cost>> {
+        let mut distances = IntMap::<Id, Cost>::default();
+        let mut heap = BinaryHeap::new();
+        distances.insert(target, Cost::default());
+        heap.push(MinScored(Cost::default(), target));
+
+        while let Some(MinScored(distance, id)) = heap.pop() {
+            if distances[&id] < distance { continue; }
+
+            for from in self.predecessors(id) {
+                let cost = self.cost_between(from, id).expect("A predecessor has a Go edge here");
+                if cost < Cost::default() {
+                    return Err(NegativeCostError { from, to: id, cost });
+                }
+
+                let next = distance + cost;
+                if distances.get(&from).is_none_or(|&d| next < d) {
+                    distances.insert(from, next);
+                    heap.push(MinScored(next, from));
+                }
+            }
+        }
+
+        Ok(distances)
+    }
+}
@@ -0,0 +1,12 @@
+pub mod shortest_paths;
+pub mod uniform_cost_iter;
+pub mod dijkstra;
+pub mod astar;
+pub mod bellman_ford;
+pub mod all_pairs;
+pub mod k_shortest_paths;
+pub mod bidirectional_dijkstra;
+pub mod landmarks;
+pub mod bottleneck;
+pub mod turn_costs;
+pub mod shortest_path_dag;
@@ -0,0 +1,220 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+use crate::path::Path;
+use crate::scored::MinScored;
+use crate::shortest_path::shortest_paths::NegativeCostError;
+
+use nohash_hasher::IntMap;
+use std::collections::{BinaryHeap, VecDeque};
+
+/*
+    Shortest-path DAG
+
+    Dijkstra that keeps every predecessor reaching a node at its minimal cost, 
+    rather than just the first found. The predecessor links form a DAG holding 
+    every shortest path out of the source, from which the number of distinct 
+    shortest paths to each node follows. Costs are compared exactly, so float 
+    costs that should tie may not.
+
+*/
+
+/// How many distinct shortest paths reach a node
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum PathCount {
+    Finite(u64),
+    /// More than fit in a u64
+    Saturated,
+    /// The node lies on or beyond a zero-cost cycle, so shortest walks to it may go round the
+    /// cycle any number of times. Only the simple paths among them are enumerated.
+    Unbounded
+}
+
+impl PathCount {
+    fn add(self, other : PathCount) -> PathCount {
+        match (self, other) {
+            (PathCount::Unbounded, _) | (_, PathCount::Unbounded) => PathCount::Unbounded,
+            (PathCount::Finite(a), PathCount::Finite(b)) => a.checked_add(b).map_or(PathCount::Saturated, PathCount::Finite),
+            _ => PathCount::Saturated
+        }
+    }
+}
+
+/// Every shortest path out of a source. Nodes that were not reached have no entries.
+#[derive(Clone, Debug)]
+pub struct ShortestPathDag<Id, Cost> where Id : Identity
+{
+    pub source: Id,
+    pub distances: IntMap<Id, Cost>,
+    /// Each node's predecessors on any of its shortest paths, with the cost of the Go edge from each
+    pub predecessors: IntMap<Id, Vec<(Id, Cost)>>,
+    /// The number of distinct shortest paths to each node
+    pub counts: IntMap<Id, PathCount>
+}
+
+impl<Id, Cost> ShortestPathDag<Id, Cost> where Id : Identity, Cost : Scalar
+{
+    /// The shortest distance from the source to id, if it was reached
+    pub fn distance(&self, id : Id) -> Option<Cost> {
+        self.distances.get(&id).copied()
+    }
+
+    /// The predecessors of id on any shortest path, with the cost of the Go edge from each
+    pub fn predecessors(&self, id : Id) -> &[(Id, Cost)] {
+        self.predecessors.get(&id).map_or(&[], |p| p.as_slice())
+    }
+
+    /// The number of distinct shortest paths to id, zero if it was not reached
+    pub fn path_count(&self, id : Id) -> PathCount {
+        self.counts.get(&id).copied().unwrap_or(PathCount::Finite(0))
+    }
+
+    /// Whether exactly one simple shortest path reaches id - as paths_to would yield
+    pub fn is_unique(&self, id : Id) -> bool {
+        match self.path_count(id) {
+            PathCount::Finite(count) => count == 1,
+            PathCount::Saturated => false,
+            PathCount::Unbounded => self.paths_to(id).take(2).count() == 1
+        }
+    }
+
+    /// Every edge (from, to) lying on some shortest path
+    pub fn edges(&self) -> impl Iterator<Item=(Id, Id)> + '_ {
+        self.predecessors.iter().flat_map(|(&to, from)| from.iter().map(move |&(from, _)| (from, to)))
+    }
+
+    /// Returns an iterator over every shortest path from the source to target, with the cost of the 
+    /// Go edge taken on each hop. Paths through zero-cost cycles are only given without the cycle.
+    /// Will return an empty iterator if target was not reached
+    pub fn paths_to(&self, target : Id) -> EqualCostPaths<'_, Id, Cost> {
+        let stack = match self.distances.contains_key(&target) {
+            true => vec![(target, 0)],
+            false => vec![]
+        };
+
+        EqualCostPaths { dag: self, stack }
+    }
+}
+
+/// Walks the predecessor DAG back from the target, yielding each path out of the source
+pub struct EqualCostPaths<'a, Id, Cost> where Id : Identity
+{
+    dag: &'a ShortestPathDag<Id, Cost>,
+    // The path back from the target, with the next predecessor to try at each step
+    stack: Vec<(Id, usize)>
+}
+
+impl<'a, Id, Cost> Iterator for EqualCostPaths<'a, Id, Cost> where Id : Identity, Cost : Scalar
+{
+    type Item = Path<Id, Cost>;
+
+    fn next(&mut self) -> Option<Path<Id, Cost>> {
+        while let Some((id, index)) = self.stack.last_mut() {
+            let id = *id;
+
+            if id == self.dag.source {
+                let mut path = Path::new(id);
+                // Each step below the source last stepped back along its previous predecessor
+                for &(to, index) in self.stack.iter().rev().skip(1) {
+                    path.push(to, self.dag.predecessors(to)[index - 1].1);
+                }
+                self.stack.pop();
+                return Some(path);
+            }
+
+            let Some(&(predecessor, _)) = self.dag.predecessors(id).get(*index) else {
+                self.stack.pop();
+                continue;
+            };
+            *index += 1;
+
+            if !self.stack.iter().any(|&(id, _)| id == predecessor) {
+                self.stack.push((predecessor, 0));
+            }
+        }
+
+        None
+    }
+}
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{ 
+    /// Returns every shortest path from source to each reachable node, and how many there are.
+    /// Returns an empty DAG if graph does not contain source.
+    pub fn shortest_path_dag(&self, source : Id) -> Result<ShortestPathDag<Id, Cost>, NegativeCostError<Id, Cost>> {
+        let mut distances = IntMap::<Id, Cost>::default();
+        let mut predecessors = IntMap::<Id, Vec<(Id, Cost)>>::default();
+        let mut heap = BinaryHeap::new();
+
+        if self.nodes.contains_key(&source) {
+            distances.insert(source, Cost::default());
+            heap.push(MinScored(Cost::default(), source));
+        }
+
+        while let Some(MinScored(distance, id)) = heap.pop() {
+            if distances[&id] < distance { continue; }
+
+            for (&neighbour, &cost) in self.nodes[&id].weighted_neighbours() {
+                if cost < Cost::default() {
+                    return Err(NegativeCostError { from: id, to: neighbour, cost });
+                }
+
+                let next = distance + cost;
+                match distances.get(&neighbour) {
+                    Some(&d) if next > d => (),
+                    Some(&d) if next == d => {
+                        let from = predecessors.entry(neighbour).or_default();
+                        if !from.iter().any(|&(from, _)| from == id) { from.push((id, cost)); }
+                    },
+                    _ => {
+                        distances.insert(neighbour, next);
+                        predecessors.insert(neighbour, vec![(id, cost)]);
+                        heap.push(MinScored(next, neighbour));
+                    }
+                }
+            }
+        }
+
+        let counts = Self::count_paths(source, &distances, &predecessors);
+        Ok(ShortestPathDag { source, distances, predecessors, counts })
+    }
+
+    /// Counts paths down the DAG in topological order. Whatever is left uncounted lies on or 
+    /// beyond a zero-cost cycle.
+    fn count_paths(source : Id, distances : &IntMap<Id, Cost>, predecessors : &IntMap<Id, Vec<(Id, Cost)>>) -> IntMap<Id, PathCount> {
+        let mut successors = IntMap::<Id, Vec<Id>>::default();
+        let mut waiting = IntMap::<Id, usize>::default();
+        for (&to, from) in predecessors.iter() {
+            waiting.insert(to, from.len());
+            for &(from, _) in from.iter() {
+                successors.entry(from).or_default().push(to);
+            }
+        }
+
+        let mut counts = IntMap::<Id, PathCount>::default();
+        let mut queue = VecDeque::new();
+        if waiting.get(&source).is_none_or(|&w| w == 0) && distances.contains_key(&source) {
+            counts.insert(source, PathCount::Finite(1));
+            queue.push_back(source);
+        }
+
+        while let Some(id) = queue.pop_front() {
+            let count = counts[&id];
+            for &next in successors.get(&id).into_iter().flatten() {
+                let total = counts.entry(next).or_insert(PathCount::Finite(0));
+                *total = total.add(count);
+
+                let remaining = waiting.get_mut(&next).expect("Successors have predecessors");
+                *remaining -= 1;
+                if *remaining == 0 { queue.push_back(next); }
+            }
+        }
+
+        for &id in distances.keys() {
+            if waiting.get(&id).is_some_and(|&w| w > 0) {
+                counts.insert(id, PathCount::Unbounded);
+            }
+        }
+
+        counts
+    }
+}
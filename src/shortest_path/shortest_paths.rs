@@ -0,0 +1,61 @@
+use crate::types::{ Scalar, Identity };
+use crate::path::Path;
+
+use nohash_hasher::IntMap;
+use std::fmt;
+
+/// The shortest distances out of a source, and the predecessor of each node on its shortest path
+/// along with the cost of the Go edge taken from it. Nodes that were not reached have neither.
+#[derive(Clone, Debug)]
+pub struct ShortestPaths<Id, Cost> where Id : Identity
+{
+    pub source: Id,
+    pub distances: IntMap<Id, Cost>,
+    /// The source has no predecessor
+    pub predecessors: IntMap<Id, (Id, Cost)>
+}
+
+impl<Id, Cost> ShortestPaths<Id, Cost> where Id : Identity, Cost : Scalar
+{
+    /// The shortest distance from the source to id, if it was reached
+    pub fn distance(&self, id : Id) -> Option<Cost> {
+        self.distances.get(&id).copied()
+    }
+
+    /// The shortest path from the source to target, if target was reached, with the cost of
+    /// the Go edge taken on each hop.
+    pub fn path_to(&self, target : Id) -> Option<Path<Id, Cost>> {
+        if !self.distances.contains_key(&target) { return None; }
+
+        let mut hops = Vec::new();
+        let mut current = target;
+        while current != self.source {
+            let (previous, cost) = self.predecessors[&current];
+            hops.push((current, cost));
+            current = previous;
+        }
+
+        let mut path = Path::new(self.source);
+        for (id, cost) in hops.into_iter().rev() {
+            path.push(id, cost);
+        }
+
+        Some(path)
+    }
+}
+
+/// Returned when a search that requires non-negative costs meets a negative Go edge
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct NegativeCostError<Id, Cost> {
+    pub from: Id,
+    pub to: Id,
+    pub cost: Cost
+}
+
+impl<Id : fmt::Debug, Cost : fmt::Debug> fmt::Display for NegativeCostError<Id, Cost> {
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "negative cost {:?} on edge {:?} -> {:?}", self.cost, self.from, self.to)
+    }
+}
+
+impl<Id : fmt::Debug, Cost : fmt::Debug> std::error::Error for NegativeCostError<Id, Cost> {}
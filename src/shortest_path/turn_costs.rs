@@ -0,0 +1,92 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+use crate::path::Path;
+use crate::scored::MinScored;
+use crate::shortest_path::shortest_paths::NegativeCostError;
+
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+/*
+    Node and turn costs
+
+    Dijkstra where passing through a node costs something on top of the edges 
+    either side of it. Each intermediate node adds its own cost and the cost of 
+    the turn (previous, current, next) taken there. Since the turn depends on 
+    the edge arrived by, the search runs over edges rather than nodes - a node 
+    may be visited once per incoming edge. The source and target pay no node 
+    or turn cost.
+
+*/
+
+/// A search state: the edge (previous, current) just travelled
+type Arrival<Id> = (Id, Id);
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{ 
+    /// Returns the cheapest path from source to target, counting edge costs, node_cost of every node
+    /// passed through and turn_cost of every (previous, current, next) turn. Each hop's cost includes
+    /// the node and turn it leaves from. A step whose combined cost is negative is an error.
+    /// Returns None if target cannot be reached.
+    pub fn shortest_path_with_turns<NODE, TURN>(&self, source : Id, target : Id, mut node_cost : NODE, mut turn_cost : TURN) -> Result<Option<Path<Id, Cost>>, NegativeCostError<Id, Cost>> 
+    where NODE : FnMut(Id) -> Cost, TURN : FnMut(Id, Id, Id) -> Cost {
+        if !self.nodes.contains_key(&source) { return Ok(None); }
+        if source == target { return Ok(Some(Path::new(source))); }
+
+        let mut distances = HashMap::<Arrival<Id>, Cost>::new();
+        let mut predecessors = HashMap::<Arrival<Id>, Arrival<Id>>::new();
+        let mut steps = HashMap::<Arrival<Id>, Cost>::new();
+        let mut settled = HashSet::<Arrival<Id>>::new();
+        let mut heap = BinaryHeap::new();
+
+        for (&neighbour, &cost) in self.nodes[&source].weighted_neighbours() {
+            if cost < Cost::default() {
+                return Err(NegativeCostError { from: source, to: neighbour, cost });
+            }
+
+            let arrival = (source, neighbour);
+            if distances.get(&arrival).is_none_or(|&d| cost < d) {
+                distances.insert(arrival, cost);
+                steps.insert(arrival, cost);
+                heap.push(MinScored(cost, arrival));
+            }
+        }
+
+        while let Some(MinScored(distance, arrival)) = heap.pop() {
+            if !settled.insert(arrival) { continue; }
+
+            let (previous, id) = arrival;
+            if id == target {
+                let mut arrivals = vec![arrival];
+                while let Some(&earlier) = predecessors.get(&arrivals[arrivals.len() - 1]) {
+                    arrivals.push(earlier);
+                }
+
+                let mut path = Path::new(source);
+                for arrival in arrivals.into_iter().rev() {
+                    path.push(arrival.1, steps[&arrival]);
+                }
+
+                return Ok(Some(path));
+            }
+
+            let passing = node_cost(id);
+            for (&neighbour, &cost) in self.nodes[&id].weighted_neighbours() {
+                let step = cost + passing + turn_cost(previous, id, neighbour);
+                if step < Cost::default() {
+                    return Err(NegativeCostError { from: id, to: neighbour, cost: step });
+                }
+
+                let next = distance + step;
+                let onward = (id, neighbour);
+                if distances.get(&onward).is_none_or(|&d| next < d) {
+                    distances.insert(onward, next);
+                    predecessors.insert(onward, arrival);
+                    steps.insert(onward, step);
+                    heap.push(MinScored(next, onward));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
@@ -0,0 +1,79 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+use crate::scored::MinScored;
+
+use nohash_hasher::{IntMap, IntSet};
+use std::collections::BinaryHeap;
+
+/*
+    Uniform-cost iteration
+
+    A lazy Dijkstra - nodes are yielded in order of increasing accumulated Go 
+    edge cost from the source, and the search only advances as far as the 
+    caller pulls. Costs are assumed non-negative.
+
+*/
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{ 
+    /// Returns an iterator yielding (id, distance, predecessor) in order of increasing distance 
+    /// from id, stopping at nodes further than cutoff. The source has no predecessor.
+    /// Will return an empty iterator if graph does not contain node of id
+    pub fn uc_iter(&self, id : Id, cutoff : Option<Cost>) -> UniformCostIter<'_, Id, Cost> {
+        UniformCostIter::<Id, Cost>::new(id, self, cutoff)
+    }
+}
+
+pub struct UniformCostIter<'a, Id, Cost> where Id : Identity
+{
+    graph: &'a Graph<Id, Cost>,
+    heap: BinaryHeap<MinScored<Cost, (Id, Option<Id>)>>,
+    distances: IntMap<Id, Cost>,
+    set: IntSet<Id>,
+    cutoff: Option<Cost>
+}
+
+impl<'a, Id, Cost> UniformCostIter<'a, Id, Cost> where Id : Identity, Cost : Scalar
+{
+    pub fn new(id : Id, graph : &'a Graph<Id, Cost>, cutoff : Option<Cost>) -> UniformCostIter<'a, Id, Cost> {
+        let mut heap = BinaryHeap::new();
+        let mut distances = IntMap::<Id, Cost>::default();
+
+        if graph.nodes.contains_key(&id) {
+            heap.push(MinScored(Cost::default(), (id, None)));
+            distances.insert(id, Cost::default());
+        }
+
+        UniformCostIter { graph, heap, distances, set: IntSet::default(), cutoff }
+    }
+}
+
+impl<'a, Id, Cost> Iterator for UniformCostIter<'a, Id, Cost> where Id : Identity, Cost : Scalar
+{
+    type Item = (Id, Cost, Option<Id>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+
+        while let Some(MinScored(distance, (id, predecessor))) = self.heap.pop() {
+
+            // Already settled by a shorter route
+            if !self.set.insert(id) { continue; }
+
+            for (&neighbour, &cost) in self.graph.nodes[&id].weighted_neighbours() {
+                let next = distance + cost;
+
+                if self.set.contains(&neighbour) { continue; }
+                if self.cutoff.is_some_and(|cutoff| next > cutoff) { continue; }
+
+                if self.distances.get(&neighbour).is_none_or(|&d| next < d) {
+                    self.distances.insert(neighbour, next);
+                    self.heap.push(MinScored(next, (neighbour, Some(id))));
+                }
+            }
+
+            return Some((id, distance, predecessor));
+        }
+
+        None
+    }
+}
@@ -0,0 +1,111 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::edge::Edge;
+use crate::scored::MinScored;
+
+use nohash_hasher::{IntMap, IntSet};
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+/*
+    Ego graph
+
+    The subgraph induced by every node within some radius of a centre node,
+    travelling along Go edges. Edges leaving the neighbourhood are trimmed
+    from both ends, so one way edges keep their Go and NoGo halves paired.
+
+*/
+
+/// How far an ego graph reaches from its centre
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Radius {
+    /// At most this many edges travelled
+    Hops(usize),
+    /// At most this sum of edge costs. A negative or NaN radius keeps nothing.
+    Cost(f64)
+}
+
+/// How distance between nodes is measured
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Metric {
+    /// Number of edges travelled
+    Hops,
+    /// Sum of the costs of the edges travelled
+    Cost
+}
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{ 
+    /// Returns a new graph holding every node within radius of id (inclusive), and every edge 
+    /// between them. Ids are kept from the original graph. Cost distances assume non-negative costs.
+    /// Will return an empty graph if graph does not contain node of id
+    pub fn ego_graph(&self, id : Id, radius : Radius) -> Graph<Id, Cost> {
+
+        let members = match radius {
+            Radius::Hops(hops) => self.within_hops(id, hops),
+            Radius::Cost(cost) => self.within_cost(id, cost)
+        };
+
+        let nodes : HashMap<Id, Node<Id, Cost>> = members
+            .iter()
+            .map(|member| (*member, Node::from_edges(
+                self.nodes[member]
+                    .edges
+                    .iter()
+                    .filter(|&edge| match edge {
+                        | Edge::Go { to, .. } => members.contains(to),
+                        | Edge::NoGo { to } => members.contains(to)
+                    })
+                    .cloned()
+                    .collect())))
+            .collect();
+
+        Graph::from_nodes(nodes)
+    }
+
+    fn within_hops(&self, id : Id, radius : usize) -> IntSet<Id> {
+        let mut set = IntSet::<Id>::default();
+        if !self.nodes.contains_key(&id) { return set; }
+
+        let mut queue = VecDeque::from([(id, 0usize)]);
+        set.insert(id);
+
+        while let Some((current, depth)) = queue.pop_front() {
+            if depth + 1 > radius { continue; }
+
+            for &neighbour in self.nodes[&current].neighbours() {
+                if set.insert(neighbour) {
+                    queue.push_back((neighbour, depth + 1));
+                }
+            }
+        }
+
+        set
+    }
+
+    fn within_cost(&self, id : Id, radius : f64) -> IntSet<Id> {
+        let mut set = IntSet::<Id>::default();
+        if !self.nodes.contains_key(&id) || radius.is_nan() || radius < 0.0 { return set; }
+
+        let mut distances = IntMap::<Id, Cost>::default();
+        let mut heap = BinaryHeap::new();
+        distances.insert(id, Cost::default());
+        heap.push(MinScored(Cost::default(), id));
+
+        while let Some(MinScored(distance, current)) = heap.pop() {
+            if !set.insert(current) { continue; }
+
+            for (&neighbour, &cost) in self.nodes[&current].weighted_neighbours() {
+                let next = distance + cost;
+                if next.as_f64() > radius || set.contains(&neighbour) { continue; }
+
+                if distances.get(&neighbour).is_none_or(|&d| next < d) {
+                    distances.insert(neighbour, next);
+                    heap.push(MinScored(next, neighbour));
+                }
+            }
+        }
+
+        set
+    }
+}
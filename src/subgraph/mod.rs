@@ -0,0 +1 @@
+pub mod ego_graph;
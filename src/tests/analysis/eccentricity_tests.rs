@@ -0,0 +1,155 @@
+use crate::graph::Graph;
+use crate::subgraph::ego_graph::Metric;
+use crate::distance_measures::eccentricity::Length;
+
+// A path 0 - 1 - ... - (n - 1) of unit cost edges
+fn create_graph(n : u32) -> Graph<u32, f32> {
+    Graph::<u32, f32>::from_sparse((0..n).collect(), (1..n).map(|i| (i - 1, i, 1.0)).collect())
+}
+
+// A tree: a spine 0 - 1 - 2 - 3 - 4 with a branch 2 - 5 - 6 and costly leaf 4 - 7
+fn create_tree() -> Graph<u32, u32> {
+    let mut graph = Graph::<u32, u32>::from_sparse((0..8).collect(), vec![]);
+
+    graph.connect_nodes(0, 1, 1);
+    graph.connect_nodes(1, 2, 1);
+    graph.connect_nodes(2, 3, 1);
+    graph.connect_nodes(3, 4, 1);
+    graph.connect_nodes(2, 5, 1);
+    graph.connect_nodes(5, 6, 1);
+    graph.connect_nodes(4, 7, 10);
+
+    graph
+}
+
+#[test]
+fn eccentricities_of_a_path() {
+    let graph = create_graph(5);
+    let eccentricities = graph.eccentricities(Metric::Hops).unwrap();
+
+    assert_eq!(eccentricities.get(0), Some(Length::Hops(4)));
+    assert_eq!(eccentricities.get(2), Some(Length::Hops(2)));
+    assert_eq!(eccentricities.diameter(), Some(Length::Hops(4)));
+    assert_eq!(eccentricities.radius(), Some(Length::Hops(2)));
+    assert_eq!(eccentricities.center(), [2]);
+
+    let mut periphery = eccentricities.periphery();
+    periphery.sort();
+    assert_eq!(periphery, [0, 4]);
+
+    assert_eq!(graph.eccentricity(1, Metric::Cost).unwrap(), Some(Length::Cost(3.0)));
+    assert_eq!(graph.eccentricity(9, Metric::Cost).unwrap(), None);
+}
+
+#[test]
+fn hops_and_cost_disagree() {
+    let graph = create_tree();
+
+    let hops = graph.diameter(Metric::Hops).unwrap().unwrap();
+    assert_eq!(hops.length, Length::Hops(5));
+    assert_eq!(hops.path.len(), 5);
+
+    let cost = graph.diameter(Metric::Cost).unwrap().unwrap();
+    assert_eq!(cost.length, Length::Cost(14));
+    assert_eq!(cost.path.total_cost(), 14);
+
+    // The costly leaf is at one end; the other is either end of the spine's far side
+    let (a, b) = cost.endpoints;
+    assert!(a == 7 || b == 7);
+    assert!([0, 6].contains(&a) || [0, 6].contains(&b));
+    assert_eq!((cost.path.start(), cost.path.end()), cost.endpoints);
+
+    // The costly leaf drags the center towards it
+    assert_eq!(graph.eccentricities(Metric::Cost).unwrap().center(), [4]);
+    let mut center = graph.eccentricities(Metric::Hops).unwrap().center();
+    center.sort();
+    assert_eq!(center, [2, 3]);
+}
+
+#[test]
+fn double_sweep_finds_tree_diameter() {
+    let graph = create_tree();
+
+    for start in 0..8 {
+        for metric in [Metric::Hops, Metric::Cost] {
+            let exact = graph.diameter(metric).unwrap().unwrap();
+            let sweep = graph.diameter_double_sweep(start, metric).unwrap().unwrap();
+
+            assert_eq!(sweep.length, exact.length);
+            assert_eq!(sweep.path.validate(&graph), Ok(()));
+        }
+    }
+
+    assert!(graph.diameter_double_sweep(99, Metric::Hops).unwrap().is_none());
+}
+
+#[test]
+fn double_sweep_is_a_lower_bound() {
+    // A cycle with chords, where one sweep may fall short
+    let mut graph = create_graph(12);
+    graph.connect_nodes(11, 0, 1.0);
+    graph.connect_nodes(0, 6, 1.0);
+    graph.connect_nodes(3, 9, 1.0);
+
+    let exact = graph.diameter(Metric::Hops).unwrap().unwrap();
+    for start in 0..12 {
+        let sweep = graph.diameter_double_sweep(start, Metric::Hops).unwrap().unwrap();
+        assert!(sweep.length <= exact.length);
+    }
+}
+
+#[test]
+fn unreachable_nodes_are_infinitely_far() {
+    let mut graph = create_graph(4);
+    graph.disconnect_nodes(1, 2);
+    graph.one_way_connect_nodes(1, 2, 1.0);
+
+    let eccentricities = graph.eccentricities(Metric::Hops).unwrap();
+
+    assert_eq!(eccentricities.get(0), Some(Length::Hops(3)));
+    assert_eq!(eccentricities.get(3), Some(Length::Infinite));
+    assert_eq!(eccentricities.center(), [1]);
+    assert_eq!(eccentricities.diameter(), Some(Length::Infinite));
+    assert_eq!(eccentricities.diameter().unwrap().as_f64(), f64::INFINITY);
+    assert!(graph.diameter(Metric::Hops).unwrap().is_none());
+
+    // The second sweep, out of 3, reaches only 2
+    let sweep = graph.diameter_double_sweep(0, Metric::Hops).unwrap().unwrap();
+    assert_eq!(sweep.endpoints, (0, 3));
+    assert_eq!(sweep.path.nodes(), [0, 1, 2, 3]);
+}
+
+#[test]
+fn empty_and_negative_graphs() {
+    let graph = Graph::<u32, i32>::from_sparse(vec![], vec![]);
+    assert!(graph.diameter(Metric::Cost).unwrap().is_none());
+    assert_eq!(graph.eccentricities(Metric::Cost).unwrap().radius(), None);
+
+    let mut graph = Graph::<u32, i32>::from_sparse((0..2).collect(), vec![]);
+    graph.connect_nodes(0, 1, -1);
+    assert!(graph.eccentricities(Metric::Cost).is_err());
+    assert!(graph.eccentricities(Metric::Hops).is_ok());
+}
+
+#[test]
+fn large_integer_costs_stay_exact() {
+    // Beyond 2^53 neighbouring u64 costs are indistinguishable as f64
+    let big = 1u64 << 60;
+    let mut graph = Graph::<u32, u64>::from_sparse((0..3).collect(), vec![]);
+    graph.connect_nodes(0, 1, big);
+    graph.connect_nodes(1, 2, big + 1);
+
+    let diameter = graph.diameter(Metric::Cost).unwrap().unwrap();
+    assert_eq!(diameter.length, Length::Cost(2 * big + 1));
+    assert_eq!(diameter.length, Length::Cost(diameter.path.total_cost()));
+
+    // As f64, big and big + 1 would tie and every node would join the center
+    let mut graph = Graph::<u32, u64>::from_sparse((0..3).collect(), vec![]);
+    graph.connect_nodes(0, 1, big + 1);
+    graph.connect_nodes(0, 2, big);
+    graph.connect_nodes(1, 2, big);
+
+    let eccentricities = graph.eccentricities(Metric::Cost).unwrap();
+    assert_eq!(eccentricities.get(0), Some(Length::Cost(big + 1)));
+    assert_eq!(eccentricities.center(), [2]);
+}
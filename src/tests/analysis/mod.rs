@@ -0,0 +1,2 @@
+pub mod topological_sort_tests;
+pub mod eccentricity_tests;
@@ -0,0 +1,93 @@
+use crate::graph::Graph;
+use crate::topological::topological_sort::CycleError;
+
+fn create_graph(n : u16) -> Graph<u16, f32> {
+    let ids: Vec<u16> = (0..n).collect();
+    let edges: Vec<(u16, u16, f32)> = Vec::new();
+    Graph::<u16, f32>::from_sparse(ids, edges)
+}
+
+fn create_dag() -> Graph<u16, f32> {
+    let mut graph = create_graph(6);
+
+    graph.one_way_connect_nodes(5, 2, 1.0);
+    graph.one_way_connect_nodes(5, 0, 1.0);
+    graph.one_way_connect_nodes(4, 0, 1.0);
+    graph.one_way_connect_nodes(4, 1, 1.0);
+    graph.one_way_connect_nodes(2, 3, 1.0);
+    graph.one_way_connect_nodes(3, 1, 1.0);
+
+    graph
+}
+
+fn assert_ordered(graph : &Graph<u16, f32>, order : &[u16]) {
+    assert_eq!(order.len(), graph.nodes.len());
+
+    let position = |id : &u16| order.iter().position(|o| o == id).unwrap();
+    for (from, node) in graph.nodes.iter() {
+        for to in node.neighbours() {
+            assert!(position(from) < position(to));
+        }
+    }
+}
+
+fn assert_is_cycle(graph : &Graph<u16, f32>, error : CycleError<u16>) {
+    let cycle = error.cycle;
+    assert!(!cycle.is_empty());
+
+    for i in 0..cycle.len() {
+        let next = cycle[(i + 1) % cycle.len()];
+        assert!(graph.nodes[&cycle[i]].neighbours().any(|&n| n == next));
+    }
+}
+
+#[test]
+fn orders_every_go_edge_forwards() {
+    let graph = create_dag();
+
+    assert_ordered(&graph, &graph.topological_sort().unwrap());
+    assert_ordered(&graph, &graph.df_topological_sort().unwrap());
+}
+
+#[test]
+fn lexicographic_order_is_smallest() {
+    let graph = create_dag();
+
+    assert_eq!(graph.lexicographic_topological_sort().unwrap(), [4, 5, 0, 2, 3, 1]);
+}
+
+#[test]
+fn groups_into_generations() {
+    let graph = create_dag();
+
+    let mut generations = graph.topological_generations().unwrap();
+    for generation in generations.iter_mut() {
+        generation.sort();
+    }
+
+    assert_eq!(generations, [vec![4, 5], vec![0, 2], vec![3], vec![1]]);
+}
+
+#[test]
+fn reports_a_cycle() {
+    let mut graph = create_dag();
+
+    // 3 -> 1 -> 5 -> 2 -> 3
+    graph.one_way_connect_nodes(1, 5, 1.0);
+
+    assert_is_cycle(&graph, graph.topological_sort().unwrap_err());
+    assert_is_cycle(&graph, graph.lexicographic_topological_sort().unwrap_err());
+    assert_is_cycle(&graph, graph.topological_generations().unwrap_err());
+    assert_is_cycle(&graph, graph.df_topological_sort().unwrap_err());
+}
+
+#[test]
+fn two_way_edges_and_self_loops_are_cycles() {
+    let mut graph = create_graph(3);
+    graph.connect_nodes(0, 1, 1.0);
+    assert_eq!(graph.lexicographic_topological_sort().unwrap_err().cycle.len(), 2);
+
+    let mut graph = create_graph(3);
+    graph.one_way_connect_nodes(2, 2, 1.0);
+    assert_eq!(graph.df_topological_sort(), Err(CycleError { cycle: vec![2] }));
+}
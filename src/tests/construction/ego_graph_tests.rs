@@ -0,0 +1,81 @@
+use crate::graph::Graph;
+use crate::edge::Edge;
+use crate::subgraph::ego_graph::Radius;
+
+fn create_graph(n : u16) -> Graph<u16, f32> {
+    let ids: Vec<u16> = (0..n).collect();
+    let edges: Vec<(u16, u16, f32)> = Vec::new();
+    Graph::<u16, f32>::from_sparse(ids, edges)
+}
+
+fn sorted_ids(graph : &Graph<u16, f32>) -> Vec<u16> {
+    let mut ids : Vec<u16> = graph.nodes.keys().cloned().collect();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn keeps_nodes_within_hops() {
+    let mut graph = create_graph(6);
+
+    for i in 0..5 {
+        graph.connect_nodes(i, i + 1, 10.0);
+    }
+
+    let ego = graph.ego_graph(2, Radius::Hops(1));
+
+    assert_eq!(sorted_ids(&ego), [1, 2, 3]);
+
+    // 1 and 3 lose their edges to 0 and 4
+    assert_eq!(ego.nodes[&1].edges, [Edge::Go { to: 2, cost: 10.0 }]);
+    assert_eq!(ego.nodes[&2].edges.len(), 2);
+    assert_eq!(ego.nodes[&3].edges, [Edge::Go { to: 2, cost: 10.0 }]);
+}
+
+#[test]
+fn keeps_nodes_within_cost() {
+    let mut graph = create_graph(5);
+
+    graph.connect_nodes(0, 1, 1.0);
+    graph.connect_nodes(1, 2, 1.0);
+    graph.connect_nodes(0, 3, 5.0);
+    graph.connect_nodes(2, 4, 0.5);
+
+    let ego = graph.ego_graph(0, Radius::Cost(2.5));
+
+    assert_eq!(sorted_ids(&ego), [0, 1, 2, 4]);
+    assert!(ego.nodes[&0].neighbours().all(|&n| n == 1));
+}
+
+#[test]
+fn trims_one_way_edges_consistently() {
+    let mut graph = create_graph(3);
+
+    graph.one_way_connect_nodes(0, 1, 1.0);
+    graph.one_way_connect_nodes(2, 1, 1.0);
+
+    let ego = graph.ego_graph(0, Radius::Hops(1));
+
+    // 2 is only reachable against the direction of its edge
+    assert_eq!(sorted_ids(&ego), [0, 1]);
+    assert_eq!(ego.nodes[&0].edges, [Edge::Go { to: 1, cost: 1.0 }]);
+    assert_eq!(ego.nodes[&1].edges, [Edge::NoGo { to: 0 }]);
+}
+
+#[test]
+fn empty_when_centre_non_existent() {
+    let graph = create_graph(3);
+
+    assert_eq!(graph.ego_graph(99, Radius::Hops(10)).nodes.len(), 0);
+    assert_eq!(graph.ego_graph(0, Radius::Cost(0.0)).nodes.len(), 1);
+}
+
+#[test]
+fn invalid_cost_radius_keeps_nothing() {
+    let mut graph = create_graph(2);
+    graph.connect_nodes(0, 1, 1.0);
+
+    assert_eq!(graph.ego_graph(0, Radius::Cost(f64::NAN)).nodes.len(), 0);
+    assert_eq!(graph.ego_graph(0, Radius::Cost(-1.0)).nodes.len(), 0);
+    assert_eq!(graph.ego_graph(0, Radius::Hops(0)).nodes.len(), 1);
+}
@@ -1,2 +1,2 @@
-
-pub mod from_sparse_tests;
\ No newline at end of file
+pub mod from_sparse_tests;
+pub mod ego_graph_tests;
\ No newline at end of file
@@ -0,0 +1,65 @@
+use crate::graph::Graph;
+
+fn create_graph(n : u16) -> Graph<u16, f32> {
+    let ids: Vec<u16> = (0..n).collect();
+    let edges: Vec<(u16, u16, f32)> = Vec::new();
+    Graph::<u16, f32>::from_sparse(ids, edges)
+}
+
+#[test]
+fn finds_fewest_hops() {
+    let mut graph = create_graph(6);
+
+    // long way round 0 -> 1 -> 2 -> 3 -> 4, short cut 0 -> 5 -> 4
+    graph.connect_nodes(0, 1, 1.0);
+    graph.connect_nodes(1, 2, 1.0);
+    graph.connect_nodes(2, 3, 1.0);
+    graph.connect_nodes(3, 4, 1.0);
+    graph.connect_nodes(0, 5, 1.0);
+    graph.connect_nodes(5, 4, 1.0);
+
+    let path = graph.bf_bidirectional(0, 4).unwrap();
+
+    assert_eq!(path.len(), 2);
+    assert_eq!(path.nodes(), [0, 5, 4]);
+    assert_eq!(path.total_cost(), 2.0);
+}
+
+#[test]
+fn follows_one_way_edges_in_their_direction() {
+    let mut graph = create_graph(4);
+
+    graph.one_way_connect_nodes(0, 1, 1.0);
+    graph.one_way_connect_nodes(1, 2, 1.0);
+    graph.one_way_connect_nodes(2, 3, 1.0);
+
+    assert_eq!(graph.bf_bidirectional(0, 3).unwrap().nodes(), [0, 1, 2, 3]);
+    assert_eq!(graph.bf_bidirectional(3, 0), None);
+}
+
+#[test]
+fn matches_breadth_first_depth_on_a_grid() {
+    let size = 10;
+    let mut graph = create_graph(size * size);
+
+    for i in 0..size {
+        for j in 0..size {
+            if i + 1 < size { graph.connect_nodes(i * size + j, (i + 1) * size + j, 1.0); }
+            if j + 1 < size { graph.connect_nodes(i * size + j, i * size + j + 1, 1.0); }
+        }
+    }
+
+    let path = graph.bf_bidirectional(0, size * size - 1).unwrap();
+
+    assert_eq!(path.len(), 2 * (size as usize - 1));
+    assert_eq!(path.validate(&graph), Ok(()));
+}
+
+#[test]
+fn source_is_target_or_missing() {
+    let graph = create_graph(2);
+
+    assert_eq!(graph.bf_bidirectional(0, 0).unwrap().nodes(), [0]);
+    assert_eq!(graph.bf_bidirectional(0, 1), None);
+    assert_eq!(graph.bf_bidirectional(0, 99), None);
+}
@@ -0,0 +1,80 @@
+use crate::graph::Graph;
+
+fn create_graph(n : u16) -> Graph<u16, f32> {
+    let ids: Vec<u16> = (0..n).collect();
+    let edges: Vec<(u16, u16, f32)> = Vec::new();
+    Graph::<u16, f32>::from_sparse(ids, edges)
+}
+
+#[test]
+fn walks_identically_to_bf_iter() {
+    let mut graph = create_graph(7);
+
+    graph.connect_nodes(0, 1, 1.0);
+    graph.connect_nodes(0, 2, 1.0);
+    graph.connect_nodes(1, 3, 1.0);
+    graph.connect_nodes(1, 4, 1.0);
+    graph.connect_nodes(2, 5, 1.0);
+    graph.connect_nodes(2, 6, 1.0);
+
+    let mut walker = graph.bf_walker(0);
+    let mut walked = Vec::new();
+    while let Some(id) = walker.next(&graph) {
+        walked.push(id);
+    }
+
+    let iter : Vec<u16> = graph.bf_iter(&0).cloned().collect();
+
+    assert_eq!(walked, iter);
+}
+
+#[test]
+fn starting_at_non_existent_node_gives_empty_walk() {
+    let graph = create_graph(4);
+
+    let mut walker = graph.bf_walker(99);
+
+    assert_eq!(walker.next(&graph), None);
+}
+
+#[test]
+fn allows_destroying_nodes_mid_walk() {
+    let mut graph = create_graph(100);
+
+    for i in 0..99 {
+        graph.connect_nodes(i, i + 1, 1.0);
+    }
+
+    let mut walker = graph.bf_walker(0);
+    let mut count = 0;
+    while let Some(id) = walker.next(&graph) {
+        graph.destroy_node(id);
+        count += 1;
+    }
+
+    assert_eq!(count, 100);
+    assert_eq!(graph.nodes.len(), 0);
+}
+
+#[test]
+fn skips_nodes_removed_after_being_queued() {
+    let mut graph = create_graph(5);
+
+    graph.connect_nodes(0, 1, 1.0);
+    graph.connect_nodes(0, 2, 1.0);
+    graph.connect_nodes(1, 3, 1.0);
+    graph.connect_nodes(2, 4, 1.0);
+
+    let mut walker = graph.bf_walker(0);
+    assert_eq!(walker.next(&graph), Some(0));
+
+    // 1 and 2 are already queued
+    graph.destroy_node(2);
+
+    let mut walked = Vec::new();
+    while let Some(id) = walker.next(&graph) {
+        walked.push(id);
+    }
+
+    assert_eq!(walked, [1, 3]);
+}
@@ -0,0 +1,49 @@
+use crate::graph::Graph;
+
+fn create_graph(n : u16) -> Graph<u16, f32> {
+    let ids: Vec<u16> = (0..n).collect();
+    let edges: Vec<(u16, u16, f32)> = Vec::new();
+    Graph::<u16, f32>::from_sparse(ids, edges)
+}
+
+#[test]
+fn walks_identically_to_df_iter() {
+    let mut graph = create_graph(7);
+
+    graph.connect_nodes(0, 1, 1.0);
+    graph.connect_nodes(0, 2, 1.0);
+    graph.connect_nodes(1, 3, 1.0);
+    graph.connect_nodes(1, 4, 1.0);
+    graph.connect_nodes(2, 5, 1.0);
+    graph.connect_nodes(2, 6, 1.0);
+
+    let mut walker = graph.df_walker(0);
+    let mut walked = Vec::new();
+    while let Some(id) = walker.next(&graph) {
+        walked.push(id);
+    }
+
+    assert_eq!(walked, [0, 2, 6, 5, 1, 4, 3]);
+}
+
+#[test]
+fn follows_edges_rewired_mid_walk() {
+    let mut graph = create_graph(4);
+
+    graph.connect_nodes(0, 1, 1.0);
+    graph.connect_nodes(1, 2, 1.0);
+
+    let mut walker = graph.df_walker(0);
+    assert_eq!(walker.next(&graph), Some(0));
+
+    // Rewire 1 away from 2 and on to 3 before it is expanded
+    graph.disconnect_nodes(1, 2);
+    graph.connect_nodes(1, 3, 1.0);
+
+    let mut walked = Vec::new();
+    while let Some(id) = walker.next(&graph) {
+        walked.push(id);
+    }
+
+    assert_eq!(walked, [1, 3]);
+}
@@ -0,0 +1,49 @@
+use crate::graph::Graph;
+
+fn create_graph(n : u16) -> Graph<u16, f32> {
+    let ids: Vec<u16> = (0..n).collect();
+    let edges: Vec<(u16, u16, f32)> = Vec::new();
+    Graph::<u16, f32>::from_sparse(ids, edges)
+}
+
+#[test]
+fn traverses_depth_first_within_limit() {
+    let mut graph = create_graph(7);
+
+    graph.connect_nodes(0, 1, 1.0);
+    graph.connect_nodes(0, 2, 1.0);
+    graph.connect_nodes(1, 3, 1.0);
+    graph.connect_nodes(1, 4, 1.0);
+    graph.connect_nodes(2, 5, 1.0);
+    graph.connect_nodes(2, 6, 1.0);
+
+    let path : Vec<(u16, usize)> = graph.df_iter_limited(0, 2).collect();
+    assert_eq!(path, [(0, 0), (2, 1), (6, 2), (5, 2), (1, 1), (4, 2), (3, 2)]);
+
+    let path : Vec<(u16, usize)> = graph.df_iter_limited(0, 1).collect();
+    assert_eq!(path, [(0, 0), (2, 1), (1, 1)]);
+}
+
+#[test]
+fn finds_nodes_first_reached_by_a_long_branch() {
+    let mut graph = create_graph(5);
+
+    // 0 -> 1 -> 2 -> 3 is taken first, but 3 is one hop from 0 and 4 hangs off 3
+    graph.one_way_connect_nodes(0, 3, 1.0);
+    graph.one_way_connect_nodes(0, 1, 1.0);
+    graph.one_way_connect_nodes(1, 2, 1.0);
+    graph.one_way_connect_nodes(2, 3, 1.0);
+    graph.one_way_connect_nodes(3, 4, 1.0);
+
+    let mut found : Vec<u16> = graph.df_iter_limited(0, 2).map(|(id, _)| id).collect();
+    found.sort();
+
+    assert_eq!(found, [0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn starting_at_non_existent_node_gives_empty_iter() {
+    let graph = create_graph(4);
+
+    assert_eq!(graph.df_iter_limited(99, 3).count(), 0);
+}
@@ -0,0 +1,44 @@
+use crate::graph::Graph;
+
+fn create_graph(n : u16) -> Graph<u16, f32> {
+    let ids: Vec<u16> = (0..n).collect();
+    let edges: Vec<(u16, u16, f32)> = Vec::new();
+    Graph::<u16, f32>::from_sparse(ids, edges)
+}
+
+#[test]
+fn yields_nodes_by_increasing_depth() {
+    let mut graph = create_graph(7);
+
+    graph.connect_nodes(0, 1, 1.0);
+    graph.connect_nodes(0, 2, 1.0);
+    graph.connect_nodes(1, 3, 1.0);
+    graph.connect_nodes(1, 4, 1.0);
+    graph.connect_nodes(2, 5, 1.0);
+    graph.connect_nodes(2, 6, 1.0);
+
+    let path : Vec<(u16, usize)> = graph.df_iter_deepening(0).collect();
+
+    assert_eq!(path, [(0, 0), (2, 1), (1, 1), (6, 2), (5, 2), (4, 2), (3, 2)]);
+}
+
+#[test]
+fn depths_match_hop_distance_on_cycles() {
+    let mut graph = create_graph(6);
+
+    for i in 0..6 {
+        graph.connect_nodes(i, (i + 1) % 6, 1.0);
+    }
+
+    let mut path : Vec<(u16, usize)> = graph.df_iter_deepening(0).collect();
+    path.sort();
+
+    assert_eq!(path, [(0, 0), (1, 1), (2, 2), (3, 3), (4, 2), (5, 1)]);
+}
+
+#[test]
+fn starting_at_non_existent_node_gives_empty_iter() {
+    let graph = create_graph(4);
+
+    assert_eq!(graph.df_iter_deepening(99).count(), 0);
+}
@@ -4,4 +4,14 @@ pub mod breadth_first_iter_tests;
 pub mod breadth_first_search_tests;
 pub mod breadth_first_tests;
 pub mod depth_first_into_iter_tests;
-pub mod depth_first_iter_tests;
\ No newline at end of file
+pub mod depth_first_iter_tests;
+pub mod breadth_first_walker_tests;
+pub mod depth_first_walker_tests;
+pub mod bidirectional_breadth_first_search_tests;
+pub mod depth_limited_iter_tests;
+pub mod iterative_deepening_iter_tests;
+pub mod traversal_context_tests;
+pub mod random_walk_iter_tests;
+pub mod uniform_cost_iter_tests;
+pub mod simple_paths_tests;
+pub mod parallel_breadth_first_tests;
\ No newline at end of file
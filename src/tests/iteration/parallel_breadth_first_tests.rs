@@ -0,0 +1,78 @@
+use crate::graph::Graph;
+use rand::{distributions::Uniform, prelude::*};
+use nohash_hasher::IntMap;
+
+fn random_graph(num_nodes : u32, num_edges : u32) -> Graph<u32, f32> {
+    let mut rng = StdRng::seed_from_u64(11);
+    let range = Uniform::<u32>::new(0, num_nodes);
+
+    let ids: Vec<u32> = (0..num_nodes).collect();
+    let mut graph = Graph::<u32, f32>::from_sparse(ids, vec![]);
+
+    for _ in 0..num_edges {
+        graph.one_way_connect_nodes(rng.sample(range), rng.sample(range), 1.0);
+    }
+
+    graph
+}
+
+#[test]
+fn matches_sequential_breadth_first_traversal() {
+    let graph = random_graph(8000, 24000);
+
+    let tree = graph.bf_parallel(0, 4);
+    let order : Vec<u32> = graph.bf_iter(&0).cloned().collect();
+
+    // Sequentially, each node's parent is the first node in breadth-first order to reach it
+    let mut depths = IntMap::<u32, usize>::default();
+    let mut parents = IntMap::<u32, u32>::default();
+    depths.insert(0, 0);
+    for &id in order.iter() {
+        for &neighbour in graph.nodes[&id].neighbours() {
+            if depths.contains_key(&neighbour) { continue; }
+            depths.insert(neighbour, depths[&id] + 1);
+            parents.insert(neighbour, id);
+        }
+    }
+
+    assert_eq!(order.len(), tree.depths.len());
+    assert!(order.windows(2).all(|pair| tree.depths[&pair[0]] <= tree.depths[&pair[1]]));
+    assert_eq!(tree.depths, depths);
+    assert_eq!(tree.parents, parents);
+}
+
+#[test]
+fn parents_are_one_level_up() {
+    let graph = random_graph(8000, 24000);
+
+    let tree = graph.bf_parallel(0, 4);
+
+    assert!(!tree.parents.contains_key(&0));
+    assert_eq!(tree.parents.len(), tree.depths.len() - 1);
+
+    for (child, parent) in tree.parents.iter() {
+        assert_eq!(tree.depths[parent] + 1, tree.depths[child]);
+        assert!(graph.nodes[parent].neighbours().any(|n| n == child));
+    }
+}
+
+#[test]
+fn thread_count_does_not_change_result() {
+    let graph = random_graph(5000, 20000);
+
+    let single = graph.bf_parallel(3, 1);
+    let many = graph.bf_parallel(3, 8);
+
+    assert_eq!(single.depths, many.depths);
+    assert_eq!(single.parents, many.parents);
+}
+
+#[test]
+fn starting_at_non_existent_node_gives_empty_tree() {
+    let graph = random_graph(10, 10);
+
+    let tree = graph.bf_parallel(99, 4);
+
+    assert!(tree.depths.is_empty());
+    assert!(tree.parents.is_empty());
+}
@@ -0,0 +1,111 @@
+use crate::graph::Graph;
+use crate::random_walk::random_walk_iter::StepWeighting;
+
+fn create_graph(n : u16) -> Graph<u16, f32> {
+    let ids: Vec<u16> = (0..n).collect();
+    let edges: Vec<(u16, u16, f32)> = Vec::new();
+    Graph::<u16, f32>::from_sparse(ids, edges)
+}
+
+fn star_graph() -> Graph<u16, f32> {
+    let mut graph = create_graph(4);
+
+    graph.one_way_connect_nodes(0, 1, 1.0);
+    graph.one_way_connect_nodes(0, 2, 1.0);
+    graph.one_way_connect_nodes(0, 3, 98.0);
+    for i in 1..4 {
+        graph.one_way_connect_nodes(i, 0, 1.0);
+    }
+
+    graph
+}
+
+#[test]
+fn walk_is_reproducible_from_seed() {
+    let graph = star_graph();
+
+    let a : Vec<u16> = graph.random_walk(0, 7).take(50).collect();
+    let b : Vec<u16> = graph.random_walk(0, 7).take(50).collect();
+
+    assert_eq!(a, b);
+    assert_eq!(a[0], 0);
+}
+
+#[test]
+fn every_step_follows_a_go_edge() {
+    let mut graph = create_graph(5);
+
+    graph.one_way_connect_nodes(0, 1, 1.0);
+    graph.connect_nodes(1, 2, 1.0);
+    graph.connect_nodes(2, 3, 1.0);
+    graph.one_way_connect_nodes(3, 1, 1.0);
+    graph.one_way_connect_nodes(4, 0, 1.0);
+
+    let walk : Vec<u16> = graph.random_walk(0, 1).take(200).collect();
+
+    for step in walk.windows(2) {
+        assert!(graph.nodes[&step[0]].neighbours().any(|&n| n == step[1]));
+    }
+}
+
+#[test]
+fn weights_steps_by_cost() {
+    let graph = star_graph();
+
+    let walk : Vec<u16> = graph.random_walk(0, 3)
+        .weighting(StepWeighting::Cost)
+        .take(2000)
+        .collect();
+
+    let heavy = walk.iter().filter(|&&id| id == 3).count();
+    let light = walk.iter().filter(|&&id| id == 1 || id == 2).count();
+
+    assert!(heavy > 20 * light);
+}
+
+#[test]
+fn ends_at_max_length_or_dead_end() {
+    let mut graph = create_graph(3);
+
+    graph.one_way_connect_nodes(0, 1, 1.0);
+    graph.one_way_connect_nodes(1, 2, 1.0);
+
+    assert_eq!(graph.random_walk(0, 0).collect::<Vec<u16>>(), [0, 1, 2]);
+    assert_eq!(graph.random_walk(0, 0).max_length(1).collect::<Vec<u16>>(), [0, 1]);
+    assert_eq!(graph.random_walk(99, 0).count(), 0);
+}
+
+#[test]
+fn restarts_return_to_source() {
+    let mut graph = create_graph(3);
+
+    graph.one_way_connect_nodes(0, 1, 1.0);
+    graph.one_way_connect_nodes(1, 2, 1.0);
+
+    let walk : Vec<u16> = graph.random_walk(0, 5)
+        .restart_probability(0.5)
+        .max_length(100)
+        .collect();
+
+    assert_eq!(walk.len(), 101);
+    assert!(walk.windows(2).all(|step| step[1] == 0 || step[1] == step[0] + 1));
+}
+
+#[test]
+fn inverse_cost_prefers_zero_cost_edges() {
+    let mut graph = create_graph(4);
+    graph.connect_nodes(0, 1, 0.0);
+    graph.connect_nodes(0, 2, 1.0);
+    graph.connect_nodes(0, 3, 0.0);
+
+    let steps : Vec<u16> = graph
+        .random_walk(0, 3)
+        .weighting(StepWeighting::InverseCost)
+        .max_length(200)
+        .collect();
+
+    // Every step out of 0 takes a zero cost edge, and both are taken
+    let outward : Vec<u16> = steps.windows(2).filter(|pair| pair[0] == 0).map(|pair| pair[1]).collect();
+    assert!(outward.iter().all(|&id| id == 1 || id == 3));
+    assert!(outward.contains(&1) && outward.contains(&3));
+}
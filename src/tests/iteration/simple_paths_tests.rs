@@ -0,0 +1,88 @@
+use crate::graph::Graph;
+
+fn create_graph(n : u16) -> Graph<u16, f32> {
+    let ids: Vec<u16> = (0..n).collect();
+    let edges: Vec<(u16, u16, f32)> = Vec::new();
+    Graph::<u16, f32>::from_sparse(ids, edges)
+}
+
+#[test]
+fn finds_every_simple_path_to_target() {
+    let mut graph = create_graph(4);
+
+    graph.connect_nodes(0, 1, 1.0);
+    graph.connect_nodes(0, 2, 1.0);
+    graph.connect_nodes(1, 2, 1.0);
+    graph.connect_nodes(1, 3, 1.0);
+    graph.connect_nodes(2, 3, 1.0);
+
+    let mut paths : Vec<Vec<u16>> = graph.simple_paths(0, |id, _| id == 3, |_, _| false).map(|path| path.nodes().to_vec()).collect();
+    paths.sort();
+
+    assert_eq!(paths, [vec![0, 1, 2, 3], vec![0, 1, 3], vec![0, 2, 1, 3], vec![0, 2, 3]]);
+}
+
+#[test]
+fn paths_carry_edge_costs() {
+    let mut graph = create_graph(3);
+
+    graph.connect_nodes(0, 1, 2.0);
+    graph.connect_nodes(1, 2, 3.0);
+    graph.one_way_connect_nodes(0, 2, 7.0);
+
+    let mut costs : Vec<f32> = graph.simple_paths(0, |id, _| id == 2, |_, _| false).map(|path| path.total_cost()).collect();
+    costs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    assert_eq!(costs, [5.0, 7.0]);
+}
+
+#[test]
+fn failure_cuts_branches() {
+    let mut graph = create_graph(4);
+
+    graph.connect_nodes(0, 1, 1.0);
+    graph.connect_nodes(0, 2, 1.0);
+    graph.connect_nodes(1, 2, 1.0);
+    graph.connect_nodes(1, 3, 1.0);
+    graph.connect_nodes(2, 3, 1.0);
+
+    // Never pass through 2, and never take more than two hops
+    let mut paths : Vec<Vec<u16>> = graph
+        .simple_paths(0, |id, _| id == 3, |id, path| id == 2 || path.len() > 2)
+        .map(|path| path.nodes().to_vec())
+        .collect();
+    paths.sort();
+
+    assert_eq!(paths, [vec![0, 1, 3]]);
+}
+
+#[test]
+fn success_sees_path_so_far() {
+    let mut graph = create_graph(6);
+
+    // Ring of 6
+    for i in 0..6 {
+        graph.connect_nodes(i, (i + 1) % 6, 1.0);
+    }
+
+    // Travel three hops from 0, whichever way round
+    let mut ends : Vec<u16> = graph
+        .simple_paths(0, |_, path| path.len() == 4, |_, _| false)
+        .map(|path| path.end())
+        .collect();
+    ends.sort();
+
+    assert_eq!(ends, [3, 3]);
+}
+
+#[test]
+fn follows_one_way_edges_and_ignores_missing_source() {
+    let mut graph = create_graph(3);
+
+    graph.one_way_connect_nodes(0, 1, 1.0);
+    graph.one_way_connect_nodes(2, 1, 1.0);
+
+    assert_eq!(graph.simple_paths(0, |id, _| id == 2, |_, _| false).count(), 0);
+    assert_eq!(graph.simple_paths(2, |id, _| id == 1, |_, _| false).map(|path| path.nodes().to_vec()).collect::<Vec<_>>(), [vec![2, 1]]);
+    assert_eq!(graph.simple_paths(99, |_, _| true, |_, _| false).count(), 0);
+}
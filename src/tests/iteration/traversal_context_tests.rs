@@ -0,0 +1,59 @@
+use crate::graph::Graph;
+use crate::traversal_context::context::TraversalContext;
+
+fn create_graph(n : u16) -> Graph<u16, f32> {
+    let ids: Vec<u16> = (0..n).collect();
+    let edges: Vec<(u16, u16, f32)> = Vec::new();
+    Graph::<u16, f32>::from_sparse(ids, edges)
+}
+
+fn connected_graph() -> Graph<u16, f32> {
+    let mut graph = create_graph(8);
+
+    graph.connect_nodes(0, 1, 1.0);
+    graph.connect_nodes(1, 2, 1.0);
+    graph.connect_nodes(2, 3, 1.0);
+    graph.connect_nodes(0, 2, 1.0);
+    graph.connect_nodes(1, 3, 1.0);
+    graph.one_way_connect_nodes(3, 4, 1.0);
+    graph.connect_nodes(5, 6, 1.0);
+
+    graph
+}
+
+#[test]
+fn reused_context_matches_fresh_iterators() {
+    let graph = connected_graph();
+    let mut context = TraversalContext::with_capacity(graph.nodes.len());
+
+    for i in 0..8 {
+        let bf : Vec<u16> = graph.bf_iter_in(&mut context, i).collect();
+        assert_eq!(bf, graph.bf_iter(&i).cloned().collect::<Vec<u16>>());
+
+        let df : Vec<u16> = graph.df_iter_in(&mut context, i).collect();
+        assert_eq!(df, graph.df_iter(&i).cloned().collect::<Vec<u16>>());
+    }
+}
+
+#[test]
+fn abandoned_traversal_does_not_leak_into_the_next() {
+    let graph = connected_graph();
+    let mut context = TraversalContext::new();
+
+    let first = graph.bf_iter_in(&mut context, 0).next();
+    assert_eq!(first, Some(0));
+    assert!(context.visited(&1));
+
+    let count = graph.bf_iter_in(&mut context, 5).count();
+    assert_eq!(count, 2);
+    assert!(!context.visited(&1));
+}
+
+#[test]
+fn starting_at_non_existent_node_gives_empty_iter() {
+    let graph = create_graph(4);
+    let mut context = TraversalContext::new();
+
+    assert_eq!(graph.bf_iter_in(&mut context, 99).count(), 0);
+    assert_eq!(graph.df_iter_in(&mut context, 99).count(), 0);
+}
@@ -0,0 +1,67 @@
+use crate::graph::Graph;
+
+fn create_graph(n : u16) -> Graph<u16, f32> {
+    let ids: Vec<u16> = (0..n).collect();
+    let edges: Vec<(u16, u16, f32)> = Vec::new();
+    Graph::<u16, f32>::from_sparse(ids, edges)
+}
+
+fn weighted_graph() -> Graph<u16, f32> {
+    let mut graph = create_graph(5);
+
+    graph.connect_nodes(0, 1, 4.0);
+    graph.connect_nodes(0, 2, 1.0);
+    graph.connect_nodes(2, 1, 2.0);
+    graph.connect_nodes(1, 3, 1.0);
+    graph.one_way_connect_nodes(3, 4, 3.0);
+
+    graph
+}
+
+#[test]
+fn yields_in_distance_order() {
+    let graph = weighted_graph();
+
+    let visited : Vec<(u16, f32, Option<u16>)> = graph.uc_iter(0, None).collect();
+
+    assert_eq!(visited, [
+        (0, 0.0, None), 
+        (2, 1.0, Some(0)), 
+        (1, 3.0, Some(2)), 
+        (3, 4.0, Some(1)), 
+        (4, 7.0, Some(3))
+    ]);
+}
+
+#[test]
+fn stops_at_cutoff() {
+    let graph = weighted_graph();
+
+    let ids : Vec<u16> = graph.uc_iter(0, Some(3.0)).map(|(id, _, _)| id).collect();
+
+    assert_eq!(ids, [0, 2, 1]);
+}
+
+#[test]
+fn follows_one_way_edges_in_their_direction() {
+    let graph = weighted_graph();
+
+    let ids : Vec<u16> = graph.uc_iter(4, None).map(|(id, _, _)| id).collect();
+
+    assert_eq!(ids, [4]);
+    assert_eq!(graph.uc_iter(99, None).count(), 0);
+}
+
+#[test]
+fn works_with_integer_costs() {
+    let mut graph = Graph::<u8, u32>::from_sparse((0..4).collect(), vec![]);
+
+    graph.connect_nodes(0, 1, 10);
+    graph.connect_nodes(1, 2, 10);
+    graph.connect_nodes(0, 2, 15);
+    graph.connect_nodes(2, 3, 1);
+
+    let last = graph.uc_iter(0, None).last();
+
+    assert_eq!(last, Some((3, 16, Some(2))));
+}
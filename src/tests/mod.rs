@@ -1,3 +1,5 @@
 pub mod mutation;
 pub mod construction;
-pub mod iteration;
\ No newline at end of file
+pub mod iteration;
+pub mod analysis;
+pub mod shortest_path;
\ No newline at end of file
@@ -2,4 +2,5 @@ pub mod connect_nodes_tests;
 pub mod one_way_connect_nodes_tests;
 pub mod disconnect_nodes_tests;
 pub mod destroy_node_tests;
-pub mod prune_nodes_tests;
\ No newline at end of file
+pub mod prune_nodes_tests;
+pub mod prune_until_stable_tests;
\ No newline at end of file
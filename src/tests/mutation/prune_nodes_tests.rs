@@ -103,4 +103,66 @@ fn remove_all_singly_connected_nodes() {
     assert!(graph.nodes.contains_key(&102));
 
 
-}
\ No newline at end of file
+}
+
+fn create_tree() -> Graph<u16, f32> {
+    let mut graph = create_graph(7);
+
+    graph.connect_nodes(0, 1, 1.0);
+    graph.connect_nodes(0, 2, 1.0);
+    graph.connect_nodes(1, 3, 1.0);
+    graph.connect_nodes(1, 4, 1.0);
+    graph.connect_nodes(2, 5, 1.0);
+    graph.connect_nodes(2, 6, 1.0);
+
+    graph
+}
+
+#[test]
+fn returns_removed_ids_in_removal_order() {
+    let removed = create_tree().prune_nodes(0, |_, _| true);
+    assert_eq!(removed, [0, 1, 2, 3, 4, 5, 6]);
+
+    let removed = create_tree().df_prune_nodes(0, |_, _| true);
+    assert_eq!(removed, [0, 1, 3, 4, 2, 5, 6]);
+}
+
+#[test]
+fn predicate_can_capture_state() {
+    let mut graph = create_graph(10);
+
+    for i in 0..9 {
+        graph.connect_nodes(i, i + 1, 1.0);
+    }
+
+    let keep = [5u16, 7];
+    let mut calls = 0;
+
+    let removed = graph.prune_nodes(0, |id, _| {
+        calls += 1;
+        !keep.contains(&id)
+    });
+
+    assert_eq!(removed, [0, 1, 2, 3, 4]);
+    assert_eq!(calls, 6);
+    assert_eq!(graph.nodes.len(), 5);
+}
+
+#[test]
+fn predicate_does_not_see_pruned_neighbours() {
+    let mut graph = create_graph(4);
+
+    // 0 - 1 - 2, with 3 hanging off 1
+    graph.connect_nodes(0, 1, 1.0);
+    graph.connect_nodes(1, 2, 1.0);
+    graph.connect_nodes(1, 3, 1.0);
+
+    let mut seen = Vec::new();
+    graph.prune_nodes(0, |id, node| {
+        seen.push((id, node.edges.len()));
+        node.edges.len() <= 1
+    });
+
+    // 1 only sees 2 and 3 once 0 has gone
+    assert_eq!(seen, [(0, 1), (1, 2)]);
+}
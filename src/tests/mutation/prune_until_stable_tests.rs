@@ -0,0 +1,67 @@
+use crate::graph::Graph;
+
+fn create_graph(num_nodes : u16) -> Graph<u16, f32> {
+    let ids: Vec<u16> = (0..num_nodes).collect();
+    let edges: Vec<(u16, u16, f32)> = Vec::new();
+    Graph::<u16, f32>::from_sparse(ids, edges)
+}
+
+#[test]
+fn strips_dangling_chains_everywhere() {
+    let mut graph = create_graph(20);
+
+    // A loop of 0..4, with chains dangling off 0 and 2, and a separate chain 15..20
+    for i in 0..4 {
+        graph.connect_nodes(i, (i + 1) % 4, 1.0);
+    }
+    graph.connect_nodes(0, 4, 1.0);
+    for i in 4..9 {
+        graph.connect_nodes(i, i + 1, 1.0);
+    }
+    graph.connect_nodes(2, 10, 1.0);
+    graph.connect_nodes(10, 11, 1.0);
+    for i in 15..19 {
+        graph.connect_nodes(i, i + 1, 1.0);
+    }
+
+    let removed = graph.prune_until_stable(|_, node| node.edges.len() < 2);
+
+    let mut ids : Vec<u16> = graph.nodes.keys().cloned().collect();
+    ids.sort();
+
+    assert_eq!(ids, [0, 1, 2, 3]);
+    assert_eq!(removed.len(), 16);
+    for node in graph.nodes.values() {
+        assert_eq!(node.edges.len(), 2);
+    }
+}
+
+#[test]
+fn only_rechecks_neighbours_of_removed_nodes() {
+    let mut graph = create_graph(1000);
+
+    for i in 0..999 {
+        graph.connect_nodes(i, i + 1, 1.0);
+    }
+
+    let mut calls = 0;
+    graph.prune_until_stable(|_, node| {
+        calls += 1;
+        node.edges.len() < 2
+    });
+
+    assert_eq!(graph.nodes.len(), 0);
+    assert!(calls < 3000);
+}
+
+#[test]
+fn does_nothing_when_stable() {
+    let mut graph = create_graph(3);
+
+    for i in 0..3 {
+        graph.connect_nodes(i, (i + 1) % 3, 1.0);
+    }
+
+    assert!(graph.prune_until_stable(|_, node| node.edges.len() < 2).is_empty());
+    assert_eq!(graph.nodes.len(), 3);
+}
@@ -0,0 +1,131 @@
+use crate::graph::Graph;
+use crate::path::Path;
+use crate::shortest_path::all_pairs::AllPairsShortestPaths;
+use rand::{distributions::Uniform, prelude::*};
+
+fn random_graph(num_nodes : u16, num_edges : u16, min_cost : i32) -> Graph<u16, i32> {
+    let mut rng = StdRng::seed_from_u64(5);
+    let range = Uniform::<u16>::new(0, num_nodes);
+    let costs = Uniform::<i32>::new(min_cost, 20);
+
+    let mut graph = Graph::<u16, i32>::from_sparse((0..num_nodes).collect(), vec![]);
+
+    // Only edges from lower to higher ids, so negative costs can not form a cycle
+    for _ in 0..num_edges {
+        let (a, b) = (rng.sample(range), rng.sample(range));
+        if a < b { graph.one_way_connect_nodes(a, b, rng.sample(costs)); }
+    }
+
+    graph
+}
+
+fn assert_paths_cost_distance(graph : &Graph<u16, i32>, all : &AllPairsShortestPaths<u16, i32>) {
+    for &a in all.ids.iter() {
+        for &b in all.ids.iter() {
+            let Some(distance) = all.distance(a, b) else { 
+                assert_eq!(all.path(a, b), None);
+                continue; 
+            };
+
+            let path = all.path(a, b).unwrap();
+            assert_eq!(path.total_cost(), distance);
+            assert_eq!((path.start(), path.end()), (a, b));
+
+            // Every hop costs its cheapest edge
+            assert_eq!(Path::along(graph, path.nodes()), Ok(path));
+        }
+    }
+}
+
+#[test]
+fn floyd_warshall_and_johnson_match_dijkstra() {
+    let graph = random_graph(40, 200, 0);
+
+    let floyd_warshall = graph.floyd_warshall().unwrap();
+    let johnson = graph.johnson().unwrap();
+
+    for &a in floyd_warshall.ids.iter() {
+        let dijkstra = graph.dijkstra(a).unwrap();
+        for &b in floyd_warshall.ids.iter() {
+            assert_eq!(floyd_warshall.distance(a, b), dijkstra.distance(b));
+            assert_eq!(johnson.distance(a, b), dijkstra.distance(b));
+        }
+    }
+
+    assert_paths_cost_distance(&graph, &floyd_warshall);
+    assert_paths_cost_distance(&graph, &johnson);
+}
+
+#[test]
+fn handle_negative_costs() {
+    let graph = random_graph(40, 200, -10);
+
+    let floyd_warshall = graph.floyd_warshall().unwrap();
+    let johnson = graph.johnson().unwrap();
+
+    for &a in floyd_warshall.ids.iter() {
+        let bellman_ford = graph.bellman_ford(a).unwrap();
+        for &b in floyd_warshall.ids.iter() {
+            assert_eq!(floyd_warshall.distance(a, b), bellman_ford.distance(b));
+            assert_eq!(johnson.distance(a, b), bellman_ford.distance(b));
+        }
+    }
+
+    assert_paths_cost_distance(&graph, &floyd_warshall);
+    assert_paths_cost_distance(&graph, &johnson);
+}
+
+#[test]
+fn report_negative_cycles() {
+    let mut graph = random_graph(10, 30, 0);
+    graph.one_way_connect_nodes(7, 3, -100);
+    graph.one_way_connect_nodes(3, 7, 1);
+
+    assert!(graph.johnson().is_err());
+
+    let cycle = graph.floyd_warshall().unwrap_err().cycle;
+    let cost : i32 = cycle.iter().zip(cycle.iter().cycle().skip(1))
+        .map(|(&from, &to)| graph.cost_between(from, to).unwrap())
+        .sum();
+    assert!(cost < 0);
+}
+
+#[test]
+fn unknown_ids_have_no_distance() {
+    let graph = random_graph(5, 5, 0);
+    let all = graph.floyd_warshall().unwrap();
+
+    assert_eq!(all.distance(0, 99), None);
+    assert_eq!(all.path(99, 0), None);
+    assert_eq!(all.path(2, 2).unwrap().nodes(), [2]);
+}
+
+#[test]
+fn report_negative_cycles_before_overflowing() {
+    // Every shortest simple path fits in an i32, but repeating the cycle quickly would not
+    let mut graph = Graph::<u16, i32>::from_sparse((0..40).collect(), vec![]);
+    graph.one_way_connect_nodes(0, 1, -600_000_000);
+    graph.one_way_connect_nodes(1, 0, 100_000_000);
+    for i in 2..40 {
+        graph.connect_nodes(0, i, 0);
+    }
+
+    let cycle = graph.floyd_warshall().unwrap_err().cycle;
+    assert_eq!(cycle.len(), 2);
+    assert!(cycle.contains(&0) && cycle.contains(&1));
+}
+
+#[test]
+fn rebuild_paths_through_zero_cost_ties() {
+    // Many equally cheap routes, so the trees grown from different sources disagree
+    let mut graph = Graph::<u16, i32>::from_sparse((0..30).collect(), vec![]);
+    let mut rng = StdRng::seed_from_u64(5);
+    let range = Uniform::<u16>::new(0, 30);
+    for _ in 0..40 {
+        let (a, b) = (rng.sample(range), rng.sample(range));
+        if a != b { graph.connect_nodes(a, b, 0); }
+    }
+
+    assert_paths_cost_distance(&graph, &graph.floyd_warshall().unwrap());
+    assert_paths_cost_distance(&graph, &graph.johnson().unwrap());
+}
@@ -0,0 +1,74 @@
+use crate::graph::Graph;
+use crate::path::Path;
+
+const SIZE: u32 = 20;
+
+// A grid where every node connects to its right and lower neighbours
+fn create_grid() -> Graph<u32, u32> {
+    let mut graph = Graph::<u32, u32>::from_sparse((0..SIZE * SIZE).collect(), vec![]);
+
+    for i in 0..SIZE {
+        for j in 0..SIZE {
+            if i + 1 < SIZE { graph.connect_nodes(i * SIZE + j, (i + 1) * SIZE + j, 1); }
+            if j + 1 < SIZE { graph.connect_nodes(i * SIZE + j, i * SIZE + j + 1, 1); }
+        }
+    }
+
+    graph
+}
+
+fn manhattan(a : u32, b : u32) -> u32 {
+    (a / SIZE).abs_diff(b / SIZE) + (a % SIZE).abs_diff(b % SIZE)
+}
+
+#[test]
+fn finds_shortest_path_on_grid() {
+    let graph = create_grid();
+    let goal = SIZE * SIZE - 1;
+
+    let path = graph.astar(0, |id| id == goal, |id| manhattan(id, goal)).unwrap();
+
+    assert_eq!(path.total_cost(), 2 * (SIZE - 1));
+    assert_eq!(path.len() as u32, path.total_cost());
+    assert_eq!(path.start(), 0);
+    assert_eq!(path.end(), goal);
+}
+
+#[test]
+fn heuristic_reduces_expansions() {
+    let graph = create_grid();
+    let goal = 5 * SIZE + 5;
+
+    let (blind, blind_stats) = graph.astar_with_stats(0, |id| id == goal, |_| 0);
+    let (guided, guided_stats) = graph.astar_with_stats(0, |id| id == goal, |id| manhattan(id, goal));
+
+    assert_eq!(blind.unwrap().total_cost(), guided.unwrap().total_cost());
+    assert!(guided_stats.expanded < blind_stats.expanded);
+}
+
+#[test]
+fn matches_dijkstra_with_weighted_edges() {
+    let mut graph = Graph::<u16, f32>::from_sparse((0..5).collect(), vec![]);
+
+    graph.connect_nodes(0, 1, 1.0);
+    graph.connect_nodes(1, 2, 1.0);
+    graph.connect_nodes(2, 4, 1.0);
+    graph.connect_nodes(0, 3, 1.0);
+    graph.one_way_connect_nodes(3, 4, 5.0);
+
+    let path = graph.astar(0, |id| id == 4, |_| 0.0).unwrap();
+
+    assert_eq!(path.nodes(), [0, 1, 2, 4]);
+    assert_eq!(path.costs(), [1.0, 1.0, 1.0]);
+    assert_eq!(Some(path.total_cost()), graph.dijkstra(0).unwrap().distance(4));
+}
+
+#[test]
+fn none_when_goal_unreachable() {
+    let mut graph = Graph::<u16, f32>::from_sparse((0..3).collect(), vec![]);
+    graph.one_way_connect_nodes(1, 0, 1.0);
+
+    assert_eq!(graph.astar(0, |id| id == 1, |_| 0.0), None);
+    assert_eq!(graph.astar(99, |_| true, |_| 0.0), None);
+    assert_eq!(graph.astar(2, |_| true, |_| 0.0), Some(Path::new(2)));
+}
@@ -0,0 +1,105 @@
+use crate::graph::Graph;
+
+fn create_graph(n : u8) -> Graph<u8, i32> {
+    let ids: Vec<u8> = (0..n).collect();
+    Graph::<u8, i32>::from_sparse(ids, vec![])
+}
+
+fn assert_is_negative_cycle(graph : &Graph<u8, i32>, cycle : &[u8]) {
+    assert!(!cycle.is_empty());
+
+    let mut total = 0;
+    for i in 0..cycle.len() {
+        let next = cycle[(i + 1) % cycle.len()];
+        total += graph.nodes[&cycle[i]]
+            .weighted_neighbours()
+            .filter(|(&to, _)| to == next)
+            .map(|(_, &cost)| cost)
+            .min()
+            .unwrap();
+    }
+
+    assert!(total < 0);
+}
+
+#[test]
+fn handles_negative_costs() {
+    let mut graph = create_graph(5);
+
+    graph.one_way_connect_nodes(0, 1, 6);
+    graph.one_way_connect_nodes(0, 3, 7);
+    graph.one_way_connect_nodes(1, 2, 5);
+    graph.one_way_connect_nodes(1, 3, 8);
+    graph.one_way_connect_nodes(1, 4, -4);
+    graph.one_way_connect_nodes(2, 1, -2);
+    graph.one_way_connect_nodes(3, 2, -3);
+    graph.one_way_connect_nodes(3, 4, 9);
+    graph.one_way_connect_nodes(4, 0, 2);
+    graph.one_way_connect_nodes(4, 2, 7);
+
+    let paths = graph.bellman_ford(0).unwrap();
+
+    assert_eq!(paths.distance(1), Some(2));
+    assert_eq!(paths.distance(2), Some(4));
+    assert_eq!(paths.distance(3), Some(7));
+    assert_eq!(paths.distance(4), Some(-2));
+    assert_eq!(paths.path_to(4).unwrap().nodes(), [0, 3, 2, 1, 4]);
+    assert_eq!(paths.path_to(4).unwrap().costs(), [7, -3, -2, -4]);
+}
+
+#[test]
+fn matches_dijkstra_on_non_negative_costs() {
+    let mut graph = create_graph(6);
+
+    graph.connect_nodes(0, 1, 7);
+    graph.connect_nodes(0, 2, 9);
+    graph.connect_nodes(0, 5, 14);
+    graph.connect_nodes(1, 2, 10);
+    graph.connect_nodes(1, 3, 15);
+    graph.connect_nodes(2, 3, 11);
+    graph.connect_nodes(2, 5, 2);
+    graph.connect_nodes(3, 4, 6);
+    graph.connect_nodes(4, 5, 9);
+
+    let bellman_ford = graph.bellman_ford(0).unwrap();
+    let dijkstra = graph.dijkstra(0).unwrap();
+
+    assert_eq!(bellman_ford.distances, dijkstra.distances);
+}
+
+#[test]
+fn reports_the_negative_cycle() {
+    let mut graph = create_graph(6);
+
+    graph.one_way_connect_nodes(0, 1, 1);
+    graph.one_way_connect_nodes(1, 2, 1);
+    graph.one_way_connect_nodes(2, 3, -2);
+    graph.one_way_connect_nodes(3, 4, 1);
+    graph.one_way_connect_nodes(4, 2, -1);
+    graph.one_way_connect_nodes(4, 5, 1);
+
+    let error = graph.bellman_ford(0).unwrap_err();
+
+    let mut sorted = error.cycle.clone();
+    sorted.sort();
+    assert_eq!(sorted, [2, 3, 4]);
+    assert_is_negative_cycle(&graph, &error.cycle);
+}
+
+#[test]
+fn negative_two_way_edge_is_a_cycle() {
+    let mut graph = create_graph(3);
+
+    graph.connect_nodes(0, 1, 3);
+    graph.connect_nodes(1, 2, -1);
+
+    let error = graph.bellman_ford(0).unwrap_err();
+    assert_is_negative_cycle(&graph, &error.cycle);
+
+    // Unreachable cycles do not matter
+    let mut graph = create_graph(3);
+    graph.one_way_connect_nodes(1, 0, 3);
+    graph.connect_nodes(1, 2, -1);
+    assert!(graph.bellman_ford(2).is_err());
+    assert_eq!(graph.bellman_ford(0).unwrap().distances.len(), 1);
+}
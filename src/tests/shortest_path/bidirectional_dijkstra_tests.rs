@@ -0,0 +1,72 @@
+use crate::graph::Graph;
+use crate::path::Path;
+use crate::types::{ Scalar, Identity };
+use rand::{distributions::Uniform, prelude::*};
+
+// A path as its nodes and total cost, for comparing against expected routes
+fn costed<Id : Identity, Cost : Scalar>(path : Path<Id, Cost>) -> (Vec<Id>, Cost) {
+    (path.nodes().to_vec(), path.total_cost())
+}
+
+fn random_graph(num_nodes : u16, num_edges : u16) -> Graph<u16, u32> {
+    let mut rng = StdRng::seed_from_u64(17);
+    let range = Uniform::<u16>::new(0, num_nodes);
+    let costs = Uniform::<u32>::new(0, 10);
+
+    let mut graph = Graph::<u16, u32>::from_sparse((0..num_nodes).collect(), vec![]);
+
+    for i in 0..num_edges {
+        let (a, b, cost) = (rng.sample(range), rng.sample(range), rng.sample(costs));
+        match i % 3 {
+            0 => graph.connect_nodes(a, b, cost),
+            _ => graph.one_way_connect_nodes(a, b, cost)
+        }
+    }
+
+    graph
+}
+
+#[test]
+fn matches_dijkstra() {
+    let graph = random_graph(60, 150);
+
+    for source in 0..20 {
+        let dijkstra = graph.dijkstra(source).unwrap();
+
+        for target in 0..60 {
+            let result = graph.bidirectional_dijkstra(source, target).unwrap();
+
+            assert_eq!(result.as_ref().map(|path| path.total_cost()), dijkstra.distance(target));
+
+            if let Some(path) = result {
+                assert_eq!((path.start(), path.end()), (source, target));
+                assert_eq!(path.validate(&graph), Ok(()));
+            }
+        }
+    }
+}
+
+#[test]
+fn respects_one_way_edges() {
+    let mut graph = Graph::<u8, f64>::from_sparse((0..4).collect(), vec![]);
+
+    graph.one_way_connect_nodes(0, 1, 1.0);
+    graph.one_way_connect_nodes(1, 2, 1.0);
+    graph.one_way_connect_nodes(2, 3, 1.0);
+    graph.one_way_connect_nodes(3, 0, 10.0);
+
+    assert_eq!(graph.bidirectional_dijkstra(0, 3).unwrap().map(costed), Some((vec![0, 1, 2, 3], 3.0)));
+    assert_eq!(graph.bidirectional_dijkstra(3, 1).unwrap().map(costed), Some((vec![3, 0, 1], 11.0)));
+    assert_eq!(graph.bidirectional_dijkstra(2, 2).unwrap().map(costed), Some((vec![2], 0.0)));
+    assert_eq!(graph.bidirectional_dijkstra(2, 99).unwrap(), None);
+}
+
+#[test]
+fn rejects_negative_costs() {
+    let mut graph = Graph::<u8, i32>::from_sparse((0..3).collect(), vec![]);
+
+    graph.one_way_connect_nodes(0, 1, 1);
+    graph.one_way_connect_nodes(1, 2, -1);
+
+    assert!(graph.bidirectional_dijkstra(0, 2).is_err());
+}
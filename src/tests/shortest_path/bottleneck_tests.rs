@@ -0,0 +1,66 @@
+use crate::graph::Graph;
+
+// Two routes from 0 to 3: a direct pair of narrow edges and a longer wide detour
+fn create_graph() -> Graph<u16, u32> {
+    let mut graph = Graph::<u16, u32>::from_sparse((0..6).collect(), vec![]);
+
+    graph.connect_nodes(0, 1, 2);
+    graph.connect_nodes(1, 3, 9);
+    graph.connect_nodes(0, 2, 7);
+    graph.connect_nodes(2, 4, 6);
+    graph.connect_nodes(4, 3, 8);
+
+    graph
+}
+
+#[test]
+fn widest_path_maximises_smallest_edge() {
+    let graph = create_graph();
+
+    assert_eq!(graph.widest_path(0, 3).map(|(path, value)| (path.nodes().to_vec(), value)), Some((vec![0, 2, 4, 3], 6)));
+}
+
+#[test]
+fn minimax_path_minimises_largest_edge() {
+    let graph = create_graph();
+
+    assert_eq!(graph.minimax_path(0, 3).map(|(path, value)| (path.nodes().to_vec(), value)), Some((vec![0, 2, 4, 3], 8)));
+    assert_eq!(graph.minimax_path(0, 1).map(|(path, value)| (path.nodes().to_vec(), value)), Some((vec![0, 1], 2)));
+}
+
+#[test]
+fn follows_one_way_edges() {
+    let mut graph = Graph::<u16, f32>::from_sparse((0..3).collect(), vec![]);
+    graph.one_way_connect_nodes(0, 1, 5.0);
+    graph.one_way_connect_nodes(1, 2, 5.0);
+    graph.one_way_connect_nodes(2, 0, 1.0);
+
+    assert_eq!(graph.widest_path(0, 2).map(|(path, value)| (path.nodes().to_vec(), value)), Some((vec![0, 1, 2], 5.0)));
+    assert_eq!(graph.widest_path(2, 1).map(|(path, value)| (path.nodes().to_vec(), value)), Some((vec![2, 0, 1], 1.0)));
+    assert_eq!(graph.minimax_path(1, 0).map(|(path, value)| (path.nodes().to_vec(), value)), Some((vec![1, 2, 0], 5.0)));
+}
+
+#[test]
+fn unreachable_and_trivial_paths() {
+    let mut graph = create_graph();
+    graph.connect_nodes(5, 5, 1);
+
+    assert_eq!(graph.widest_path(0, 5), None);
+    assert_eq!(graph.minimax_path(0, 9), None);
+    assert_eq!(graph.widest_path(2, 2).map(|(path, value)| (path.nodes().to_vec(), value)), Some((vec![2], 0)));
+}
+
+#[test]
+fn bottleneck_matches_brute_force() {
+    let graph = create_graph();
+
+    for target in 1..5 {
+        let paths = graph.simple_paths(0, |id, _| id == target, |_, _| false);
+        let bottlenecks : Vec<(u32, u32)> = paths.map(|path| {
+            (*path.costs().iter().min().unwrap(), *path.costs().iter().max().unwrap())
+        }).collect();
+
+        assert_eq!(graph.widest_path(0, target).unwrap().1, bottlenecks.iter().map(|b| b.0).max().unwrap());
+        assert_eq!(graph.minimax_path(0, target).unwrap().1, bottlenecks.iter().map(|b| b.1).min().unwrap());
+    }
+}
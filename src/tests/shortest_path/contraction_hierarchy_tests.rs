@@ -0,0 +1,109 @@
+use crate::graph::Graph;
+use crate::edge::Edge;
+use crate::contraction_hierarchy::hierarchy::StaleHierarchyError;
+use rand::{distributions::Uniform, prelude::*};
+
+fn random_graph(num_nodes : u16, num_edges : u16) -> Graph<u16, u32> {
+    let mut rng = StdRng::seed_from_u64(23);
+    let range = Uniform::<u16>::new(0, num_nodes);
+    let costs = Uniform::<u32>::new(0, 10);
+
+    let mut graph = Graph::<u16, u32>::from_sparse((0..num_nodes).collect(), vec![]);
+
+    for i in 0..num_edges {
+        let (a, b, cost) = (rng.sample(range), rng.sample(range), rng.sample(costs));
+        match i % 2 {
+            0 => graph.connect_nodes(a, b, cost),
+            _ => graph.one_way_connect_nodes(a, b, cost)
+        }
+    }
+
+    graph
+}
+
+#[test]
+fn matches_dijkstra() {
+    let graph = random_graph(100, 250);
+    let hierarchy = graph.contraction_hierarchy().unwrap();
+
+    for source in (0..100).step_by(7) {
+        let dijkstra = graph.dijkstra(source).unwrap();
+
+        for target in 0..100 {
+            assert_eq!(hierarchy.distance(&graph, source, target).unwrap(), dijkstra.distance(target));
+        }
+    }
+}
+
+#[test]
+fn unpacks_paths_into_original_edges() {
+    let graph = random_graph(100, 250);
+    let hierarchy = graph.contraction_hierarchy().unwrap();
+
+    assert!(hierarchy.shortcut_count() > 0);
+
+    for source in (0..100).step_by(11) {
+        for target in (0..100).step_by(3) {
+            let Some(path) = hierarchy.shortest_path(&graph, source, target).unwrap() else { continue };
+
+            assert_eq!((path.start(), path.end()), (source, target));
+            assert_eq!(path.validate(&graph), Ok(()));
+            assert_eq!(Some(path.total_cost()), hierarchy.distance(&graph, source, target).unwrap());
+        }
+    }
+}
+
+#[test]
+fn rejects_queries_once_graph_changes() {
+    let mut graph = random_graph(20, 40);
+    let hierarchy = graph.contraction_hierarchy().unwrap();
+
+    assert!(hierarchy.distance(&graph, 0, 1).is_ok());
+
+    graph.connect_nodes(0, 1, 1);
+
+    assert_eq!(hierarchy.distance(&graph, 0, 1), Err(StaleHierarchyError));
+    assert!(hierarchy.shortest_path(&graph, 0, 1).is_err());
+
+    // Another graph entirely is never current either
+    let other = random_graph(20, 40);
+    assert!(hierarchy.distance(&other, 0, 1).is_err());
+}
+
+#[test]
+fn thorough_check_sees_nodes_changed_directly() {
+    let mut graph = random_graph(20, 40);
+    let hierarchy = graph.contraction_hierarchy().unwrap();
+    let version = graph.version();
+    assert_eq!(hierarchy.verify_thoroughly(&graph), Ok(()));
+
+    graph.nodes.get_mut(&0).unwrap().push_edge(Edge::Go { to: 1, cost: 1 });
+    assert_eq!(graph.version(), version);
+    assert_eq!(hierarchy.verify_thoroughly(&graph), Err(StaleHierarchyError));
+
+    // Queries only compare versions, so need the graph touched
+    assert!(hierarchy.distance(&graph, 0, 1).is_ok());
+    graph.touch();
+    assert_eq!(hierarchy.distance(&graph, 0, 1), Err(StaleHierarchyError));
+
+    // Changing a cost in place
+    let mut graph = random_graph(20, 40);
+    let hierarchy = graph.contraction_hierarchy().unwrap();
+
+    let id = *graph.nodes.keys().find(|id| graph.nodes[id].weighted_neighbours().next().is_some()).unwrap();
+    for edge in graph.nodes.get_mut(&id).unwrap().edges.iter_mut() {
+        if let Edge::Go { cost, .. } = edge { *cost += 1; }
+    }
+    assert!(hierarchy.verify_thoroughly(&graph).is_err());
+    assert!(hierarchy.check_current(&graph).is_ok());
+}
+
+#[test]
+fn rejects_negative_costs() {
+    let mut graph = Graph::<u8, i32>::from_sparse((0..3).collect(), vec![]);
+
+    graph.one_way_connect_nodes(0, 1, 1);
+    graph.one_way_connect_nodes(1, 2, -1);
+
+    assert!(graph.contraction_hierarchy().is_err());
+}
@@ -0,0 +1,77 @@
+use crate::graph::Graph;
+use crate::shortest_path::shortest_paths::NegativeCostError;
+
+fn create_graph(n : u16) -> Graph<u16, f32> {
+    let ids: Vec<u16> = (0..n).collect();
+    let edges: Vec<(u16, u16, f32)> = Vec::new();
+    Graph::<u16, f32>::from_sparse(ids, edges)
+}
+
+fn weighted_graph() -> Graph<u16, f32> {
+    let mut graph = create_graph(6);
+
+    graph.connect_nodes(0, 1, 7.0);
+    graph.connect_nodes(0, 2, 9.0);
+    graph.connect_nodes(0, 5, 14.0);
+    graph.connect_nodes(1, 2, 10.0);
+    graph.connect_nodes(1, 3, 15.0);
+    graph.connect_nodes(2, 3, 11.0);
+    graph.connect_nodes(2, 5, 2.0);
+    graph.connect_nodes(3, 4, 6.0);
+    graph.connect_nodes(4, 5, 9.0);
+
+    graph
+}
+
+#[test]
+fn finds_shortest_distances_and_paths() {
+    let graph = weighted_graph();
+
+    let paths = graph.dijkstra(0).unwrap();
+
+    assert_eq!(paths.distance(4), Some(20.0));
+    assert_eq!(paths.distance(5), Some(11.0));
+    assert_eq!(paths.path_to(4).unwrap().nodes(), [0, 2, 5, 4]);
+    assert_eq!(paths.path_to(0).unwrap().nodes(), [0]);
+}
+
+#[test]
+fn stops_once_target_settled() {
+    let graph = weighted_graph();
+
+    let paths = graph.dijkstra_to(0, 2).unwrap();
+
+    assert_eq!(paths.path_to(2).unwrap().nodes(), [0, 2]);
+    assert_eq!(paths.distance(1), Some(7.0));
+    assert_eq!(paths.distance(4), None);
+}
+
+#[test]
+fn follows_one_way_edges_with_integer_costs() {
+    let mut graph = Graph::<u8, u64>::from_sparse((0..4).collect(), vec![]);
+
+    graph.one_way_connect_nodes(0, 1, 1);
+    graph.one_way_connect_nodes(1, 2, 1);
+    graph.one_way_connect_nodes(0, 2, 5);
+    graph.one_way_connect_nodes(3, 0, 1);
+
+    let paths = graph.dijkstra(0).unwrap();
+
+    assert_eq!(paths.distance(2), Some(2));
+    assert_eq!(paths.path_to(2).unwrap().nodes(), [0, 1, 2]);
+    assert_eq!(paths.path_to(3), None);
+    assert!(graph.dijkstra(99).unwrap().distances.is_empty());
+}
+
+#[test]
+fn rejects_negative_costs() {
+    let mut graph = Graph::<u8, i32>::from_sparse((0..3).collect(), vec![]);
+
+    graph.one_way_connect_nodes(0, 1, 2);
+    graph.one_way_connect_nodes(1, 2, -1);
+
+    assert_eq!(graph.dijkstra(0).unwrap_err(), NegativeCostError { from: 1, to: 2, cost: -1 });
+
+    // Unreachable negative edges are never met
+    assert!(graph.dijkstra(2).is_ok());
+}
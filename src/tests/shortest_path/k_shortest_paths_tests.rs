@@ -0,0 +1,73 @@
+use crate::graph::Graph;
+use crate::path::Path;
+use crate::types::{ Scalar, Identity };
+
+// A path as its nodes and total cost, for comparing against expected routes
+fn costed<Id : Identity, Cost : Scalar>(path : Path<Id, Cost>) -> (Vec<Id>, Cost) {
+    (path.nodes().to_vec(), path.total_cost())
+}
+
+fn create_graph() -> Graph<u8, u32> {
+    let mut graph = Graph::<u8, u32>::from_sparse((0..6).collect(), vec![]);
+
+    graph.one_way_connect_nodes(0, 1, 3);
+    graph.one_way_connect_nodes(0, 2, 2);
+    graph.one_way_connect_nodes(1, 3, 4);
+    graph.one_way_connect_nodes(2, 1, 1);
+    graph.one_way_connect_nodes(2, 3, 2);
+    graph.one_way_connect_nodes(2, 4, 3);
+    graph.one_way_connect_nodes(3, 4, 2);
+    graph.one_way_connect_nodes(3, 5, 1);
+    graph.one_way_connect_nodes(4, 5, 2);
+
+    graph
+}
+
+#[test]
+fn ranks_paths_by_cost() {
+    let graph = create_graph();
+
+    let paths = graph.k_shortest_paths(0, 5, 3).unwrap();
+
+    assert_eq!(paths.into_iter().map(costed).collect::<Vec<_>>(), [
+        (vec![0, 2, 3, 5], 5),
+        (vec![0, 2, 4, 5], 7),
+        (vec![0, 1, 3, 5], 8)
+    ]);
+}
+
+#[test]
+fn returns_every_simple_path_when_k_is_large() {
+    let graph = create_graph();
+
+    let paths = graph.k_shortest_paths(0, 5, 100).unwrap();
+    let all = graph.simple_paths(0, |id, _| id == 5, |_, _| false).count();
+
+    assert_eq!(paths.len(), all);
+    assert!(paths.windows(2).all(|pair| pair[0].total_cost() <= pair[1].total_cost()));
+
+    for path in paths.iter() {
+        let path = path.nodes();
+        let mut unique = path.to_vec();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), path.len());
+    }
+}
+
+#[test]
+fn handles_two_way_edges_and_unreachable_targets() {
+    let mut graph = Graph::<u8, f32>::from_sparse((0..5).collect(), vec![]);
+
+    // Square 0 - 1 - 2 - 3 - 0, with 4 unconnected
+    graph.connect_nodes(0, 1, 1.0);
+    graph.connect_nodes(1, 2, 1.0);
+    graph.connect_nodes(2, 3, 1.5);
+    graph.connect_nodes(3, 0, 1.0);
+
+    let paths = graph.k_shortest_paths(0, 2, 5).unwrap();
+    assert_eq!(paths.into_iter().map(costed).collect::<Vec<_>>(), [(vec![0, 1, 2], 2.0), (vec![0, 3, 2], 2.5)]);
+
+    assert!(graph.k_shortest_paths(0, 4, 5).unwrap().is_empty());
+    assert!(graph.k_shortest_paths(0, 2, 0).unwrap().is_empty());
+}
@@ -0,0 +1,113 @@
+use crate::graph::Graph;
+use crate::shortest_path::landmarks::{LandmarkSelection, Landmarks};
+
+const SIZE: u32 = 20;
+
+// A grid of two-way streets with one-way avenues running down every other column
+fn create_grid() -> Graph<u32, u32> {
+    let mut graph = Graph::<u32, u32>::from_sparse((0..SIZE * SIZE).collect(), vec![]);
+
+    for i in 0..SIZE {
+        for j in 0..SIZE {
+            if j + 1 < SIZE { graph.connect_nodes(i * SIZE + j, i * SIZE + j + 1, 1 + (i * j) % 3); }
+            if i + 1 < SIZE {
+                if j % 2 == 0 { graph.one_way_connect_nodes(i * SIZE + j, (i + 1) * SIZE + j, 1); }
+                else { graph.connect_nodes(i * SIZE + j, (i + 1) * SIZE + j, 2); }
+            }
+        }
+    }
+
+    graph
+}
+
+#[test]
+fn matches_dijkstra() {
+    let graph = create_grid();
+
+    for selection in [LandmarkSelection::Farthest, LandmarkSelection::Random { seed: 3 }] {
+        let landmarks = graph.landmarks(4, selection).unwrap();
+        assert_eq!(landmarks.landmarks.len(), 4);
+
+        for (start, goal) in [(0, SIZE * SIZE - 1), (SIZE * SIZE - 1, 0), (7, 3 * SIZE + 15), (5 * SIZE, 5)] {
+            let expected = graph.dijkstra(start).unwrap().distance(goal);
+            let path = graph.alt(&landmarks, start, goal).unwrap();
+
+            assert_eq!(Some(path.total_cost()), expected);
+            assert_eq!((path.start(), path.end()), (start, goal));
+        }
+    }
+}
+
+#[test]
+fn bounds_are_admissible() {
+    let graph = create_grid();
+    let landmarks = graph.landmarks(3, LandmarkSelection::Farthest).unwrap();
+    let goal = 9 * SIZE + 4;
+
+    for id in 0..SIZE * SIZE {
+        if let Some(distance) = graph.dijkstra(id).unwrap().distance(goal) {
+            assert!(landmarks.lower_bound(id, goal) <= distance);
+        }
+    }
+}
+
+#[test]
+fn landmarks_reduce_expansions() {
+    let graph = create_grid();
+    let landmarks = graph.landmarks(4, LandmarkSelection::Farthest).unwrap();
+    let goal = SIZE * SIZE - 1;
+
+    let (blind, blind_stats) = graph.astar_with_stats(0, |id| id == goal, |_| 0);
+    let (guided, guided_stats) = graph.alt_with_stats(&landmarks, 0, goal);
+
+    assert_eq!(blind.unwrap().total_cost(), guided.unwrap().total_cost());
+    assert!(guided_stats.expanded < blind_stats.expanded);
+}
+
+#[test]
+fn farthest_covers_every_component() {
+    let graph = Graph::<u16, f32>::from_sparse((0..6).collect(), vec![(0, 1, 1.0), (1, 2, 1.0), (3, 4, 1.0), (4, 5, 1.0)]);
+    let landmarks = graph.landmarks(2, LandmarkSelection::Farthest).unwrap();
+
+    let components : Vec<bool> = landmarks.landmarks.iter().map(|&id| id < 3).collect();
+    assert!(components.contains(&true) && components.contains(&false));
+}
+
+#[test]
+fn save_and_load_round_trip() {
+    let mut graph = Graph::<u16, f64>::from_sparse((0..4).collect(), vec![]);
+    graph.connect_nodes(0, 1, 0.1);
+    graph.connect_nodes(1, 2, 0.7);
+    graph.one_way_connect_nodes(2, 3, 1.0 / 3.0);
+
+    let landmarks = graph.landmarks(2, LandmarkSelection::Random { seed: 1 }).unwrap();
+
+    let mut buffer = Vec::new();
+    landmarks.save(&mut buffer).unwrap();
+    let loaded = Landmarks::<u16, f64>::load(buffer.as_slice()).unwrap();
+
+    assert_eq!(loaded, landmarks);
+}
+
+#[test]
+fn load_rejects_bad_input() {
+    assert!(Landmarks::<u16, f64>::load("not landmarks".as_bytes()).is_err());
+    assert!(Landmarks::<u16, f64>::load("grafic-landmarks 1\nfrom 0 1 2.0\n".as_bytes()).is_err());
+    assert!(Landmarks::<u16, f64>::load("grafic-landmarks 1\nlandmark x\n".as_bytes()).is_err());
+}
+
+#[test]
+fn empty_graph_has_no_landmarks() {
+    let graph = Graph::<u16, f32>::from_sparse(vec![], vec![]);
+
+    assert!(graph.landmarks(3, LandmarkSelection::Farthest).unwrap().landmarks.is_empty());
+}
+
+#[test]
+fn negative_cost_is_an_error() {
+    let mut graph = Graph::<u16, i32>::from_sparse((0..3).collect(), vec![]);
+    graph.connect_nodes(0, 1, 1);
+    graph.connect_nodes(1, 2, -1);
+
+    assert!(graph.landmarks(1, LandmarkSelection::Random { seed: 0 }).is_err());
+}
@@ -0,0 +1,12 @@
+pub mod dijkstra_tests;
+pub mod astar_tests;
+pub mod bellman_ford_tests;
+pub mod all_pairs_tests;
+pub mod k_shortest_paths_tests;
+pub mod bidirectional_dijkstra_tests;
+pub mod contraction_hierarchy_tests;
+pub mod landmarks_tests;
+pub mod bottleneck_tests;
+pub mod turn_costs_tests;
+pub mod shortest_path_dag_tests;
+pub mod path_tests;
@@ -0,0 +1,116 @@
+use crate::graph::Graph;
+use crate::path::{Path, PathError};
+
+// A one way ring 0 -> 1 -> 2 -> 3 -> 0, with a two way spoke 1 - 4
+fn create_graph() -> Graph<u16, u32> {
+    let mut graph = Graph::<u16, u32>::from_sparse((0..5).collect(), vec![]);
+
+    graph.one_way_connect_nodes(0, 1, 1);
+    graph.one_way_connect_nodes(1, 2, 2);
+    graph.one_way_connect_nodes(2, 3, 3);
+    graph.one_way_connect_nodes(3, 0, 4);
+    graph.connect_nodes(1, 4, 5);
+
+    graph
+}
+
+#[test]
+fn along_takes_costs_from_graph() {
+    let graph = create_graph();
+
+    let path = Path::along(&graph, &[0, 1, 4]).unwrap();
+    assert_eq!(path.costs(), [1, 5]);
+    assert_eq!(path.total_cost(), 6);
+    assert_eq!(path.len(), 2);
+    assert_eq!(path.hops().collect::<Vec<_>>(), [(0, 1, 1), (1, 4, 5)]);
+
+    assert_eq!(Path::along(&graph, &[0, 3]), Err(PathError::MissingEdge { from: 0, to: 3 }));
+    assert_eq!(Path::<u16, u32>::along(&graph, &[]), Err(PathError::Empty));
+}
+
+#[test]
+fn validate_follows_go_edges() {
+    let graph = create_graph();
+
+    let mut path = Path::along(&graph, &[0, 1, 2]).unwrap();
+    assert_eq!(path.validate(&graph), Ok(()));
+
+    path.reverse();
+    assert_eq!(path.nodes(), [2, 1, 0]);
+    assert_eq!(path.costs(), [2, 1]);
+    assert_eq!(path.validate(&graph), Err(PathError::MissingEdge { from: 2, to: 1 }));
+}
+
+#[test]
+fn rotates_cycles() {
+    let graph = create_graph();
+
+    let mut cycle = Path::along(&graph, &[0, 1, 2, 3, 0]).unwrap();
+    assert!(cycle.is_cyclic());
+
+    cycle.rotate_to(2).unwrap();
+    assert_eq!(cycle.nodes(), [2, 3, 0, 1, 2]);
+    assert_eq!(cycle.costs(), [3, 4, 1, 2]);
+    assert_eq!(cycle.validate(&graph), Ok(()));
+
+    assert_eq!(cycle.rotate_to(4), Err(PathError::NotOnPath(4)));
+
+    let mut open = Path::along(&graph, &[0, 1, 2]).unwrap();
+    assert!(!open.is_cyclic());
+    assert!(!Path::<u16, u32>::new(0).is_cyclic());
+    assert_eq!(open.rotate_to(1), Err(PathError::NotCyclic));
+}
+
+#[test]
+fn concatenates_end_to_start() {
+    let graph = create_graph();
+
+    let mut path = Path::along(&graph, &[4, 1]).unwrap();
+    path.concat(Path::along(&graph, &[1, 2, 3]).unwrap()).unwrap();
+    assert_eq!(path.nodes(), [4, 1, 2, 3]);
+    assert_eq!(path.total_cost(), 10);
+
+    let disjoint = Path::along(&graph, &[0, 1]).unwrap();
+    assert_eq!(path.concat(disjoint), Err(PathError::Disjoint { end: 3, start: 0 }));
+}
+
+#[test]
+fn splits_at_first_visit() {
+    let graph = create_graph();
+    let path = Path::along(&graph, &[4, 1, 2, 3, 0, 1]).unwrap();
+
+    let (head, tail) = path.split_at(1).unwrap();
+    assert_eq!((head.nodes(), head.costs()), ([4, 1].as_slice(), [5].as_slice()));
+    assert_eq!(tail.nodes(), [1, 2, 3, 0, 1]);
+
+    let mut joined = head;
+    joined.concat(tail).unwrap();
+    assert_eq!(joined, path);
+
+    let (head, tail) = path.split_at(4).unwrap();
+    assert!(head.is_empty());
+    assert_eq!(tail, path);
+
+    assert!(path.split_at(9).is_none());
+}
+
+#[test]
+fn subtract_removes_shared_hops() {
+    let graph = create_graph();
+    let path = Path::along(&graph, &[4, 1, 2, 3, 0, 1]).unwrap();
+
+    // Hops are shared in either direction
+    let mut other = Path::along(&graph, &[4, 1, 2]).unwrap();
+    other.reverse();
+
+    let pieces = path.subtract(&other);
+    assert_eq!(pieces.len(), 1);
+    assert_eq!(pieces[0].nodes(), [2, 3, 0, 1]);
+    assert_eq!(pieces[0].costs(), [3, 4, 1]);
+
+    let middle = Path::along(&graph, &[2, 3]).unwrap();
+    let pieces : Vec<Vec<u16>> = path.subtract(&middle).iter().map(|p| p.nodes().to_vec()).collect();
+    assert_eq!(pieces, [vec![4, 1, 2], vec![3, 0, 1]]);
+
+    assert!(path.subtract(&path).is_empty());
+}
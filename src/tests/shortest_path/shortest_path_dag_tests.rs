@@ -0,0 +1,151 @@
+use crate::graph::Graph;
+use crate::shortest_path::shortest_path_dag::PathCount;
+use crate::path::Path;
+
+const SIZE: u32 = 4;
+
+// A grid where every node connects to its right and lower neighbours at unit cost
+fn create_grid() -> Graph<u32, u32> {
+    let mut graph = Graph::<u32, u32>::from_sparse((0..SIZE * SIZE).collect(), vec![]);
+
+    for i in 0..SIZE {
+        for j in 0..SIZE {
+            if i + 1 < SIZE { graph.one_way_connect_nodes(i * SIZE + j, (i + 1) * SIZE + j, 1); }
+            if j + 1 < SIZE { graph.one_way_connect_nodes(i * SIZE + j, i * SIZE + j + 1, 1); }
+        }
+    }
+
+    graph
+}
+
+#[test]
+fn counts_grid_paths() {
+    let graph = create_grid();
+    let dag = graph.shortest_path_dag(0).unwrap();
+
+    // Lattice paths: (i + j) choose i
+    assert_eq!(dag.path_count(0), PathCount::Finite(1));
+    assert_eq!(dag.path_count(SIZE + 1), PathCount::Finite(2));
+    assert_eq!(dag.path_count(2 * SIZE + 2), PathCount::Finite(6));
+    assert_eq!(dag.path_count(SIZE * SIZE - 1), PathCount::Finite(20));
+    assert!(dag.is_unique(SIZE - 1));
+    assert!(!dag.is_unique(SIZE * SIZE - 1));
+}
+
+#[test]
+fn paths_to_yields_every_shortest_path() {
+    let graph = create_grid();
+    let dag = graph.shortest_path_dag(0).unwrap();
+    let target = SIZE * SIZE - 1;
+
+    for path in dag.paths_to(target) {
+        assert_eq!(Path::along(&graph, path.nodes()), Ok(path));
+    }
+
+    let mut paths : Vec<Vec<u32>> = dag.paths_to(target).map(|path| path.nodes().to_vec()).collect();
+    assert_eq!(PathCount::Finite(paths.len() as u64), dag.path_count(target));
+
+    for path in paths.iter() {
+        assert_eq!(path.first(), Some(&0));
+        assert_eq!(path.last(), Some(&target));
+        assert_eq!(path.len() as u32, 2 * (SIZE - 1) + 1);
+    }
+
+    paths.sort();
+    paths.dedup();
+    assert_eq!(paths.len(), 20);
+}
+
+#[test]
+fn dag_excludes_longer_edges() {
+    let mut graph = Graph::<u16, f32>::from_sparse((0..4).collect(), vec![]);
+    graph.connect_nodes(0, 1, 1.0);
+    graph.connect_nodes(0, 2, 1.0);
+    graph.connect_nodes(1, 3, 1.0);
+    graph.connect_nodes(2, 3, 1.5);
+
+    let dag = graph.shortest_path_dag(0).unwrap();
+    let mut edges : Vec<(u16, u16)> = dag.edges().collect();
+    edges.sort();
+
+    assert_eq!(edges, [(0, 1), (0, 2), (1, 3)]);
+    assert!(dag.is_unique(3));
+    assert_eq!(dag.paths_to(3).map(|path| path.nodes().to_vec()).collect::<Vec<_>>(), [vec![0, 1, 3]]);
+    assert_eq!(dag.distance(3), Some(2.0));
+}
+
+#[test]
+fn zero_cost_cycles_are_unbounded() {
+    let mut graph = Graph::<u16, u32>::from_sparse((0..4).collect(), vec![]);
+    graph.connect_nodes(0, 1, 1);
+    graph.connect_nodes(1, 2, 0);
+    graph.connect_nodes(2, 3, 1);
+
+    let dag = graph.shortest_path_dag(0).unwrap();
+
+    assert_eq!(dag.path_count(0), PathCount::Finite(1));
+    assert_eq!(dag.path_count(3), PathCount::Unbounded);
+    assert!(dag.is_unique(3));
+    assert_eq!(dag.paths_to(3).map(|path| path.nodes().to_vec()).collect::<Vec<_>>(), [vec![0, 1, 2, 3]]);
+}
+
+#[test]
+fn unreachable_and_missing_nodes() {
+    let mut graph = Graph::<u16, i32>::from_sparse((0..3).collect(), vec![]);
+    graph.one_way_connect_nodes(0, 1, 1);
+
+    let dag = graph.shortest_path_dag(0).unwrap();
+    assert_eq!(dag.path_count(2), PathCount::Finite(0));
+    assert_eq!(dag.paths_to(2).count(), 0);
+    assert_eq!(dag.paths_to(0).collect::<Vec<_>>(), [Path::new(0)]);
+
+    assert!(graph.shortest_path_dag(9).unwrap().distances.is_empty());
+
+    graph.connect_nodes(1, 2, -1);
+    assert!(graph.shortest_path_dag(0).is_err());
+}
+
+#[test]
+fn zero_cost_edge_keeps_count_and_paths_consistent() {
+    // A diamond 0 -> {1, 2} -> 3, then a zero cost two way edge 3 - 4 and on to 5
+    let mut graph = Graph::<u16, u32>::from_sparse((0..6).collect(), vec![]);
+    graph.one_way_connect_nodes(0, 1, 1);
+    graph.one_way_connect_nodes(0, 2, 1);
+    graph.one_way_connect_nodes(1, 3, 1);
+    graph.one_way_connect_nodes(2, 3, 1);
+    graph.connect_nodes(3, 4, 0);
+    graph.one_way_connect_nodes(4, 5, 1);
+
+    let dag = graph.shortest_path_dag(0).unwrap();
+
+    assert_eq!(dag.path_count(3), PathCount::Unbounded);
+    assert_eq!(dag.path_count(5), PathCount::Unbounded);
+    assert_eq!(dag.paths_to(5).count(), 2);
+    assert!(!dag.is_unique(5));
+
+    // Without the diamond's second branch a single simple path remains
+    graph.disconnect_nodes(0, 2);
+    let dag = graph.shortest_path_dag(0).unwrap();
+
+    assert_eq!(dag.paths_to(5).count(), 1);
+    assert!(dag.is_unique(5));
+}
+
+#[test]
+fn counts_saturate() {
+    // 65 layers of two nodes, each node joined to both nodes of the next layer: 2^65 paths
+    let layers = 66;
+    let mut graph = Graph::<u16, u32>::from_sparse((0..2 * layers).collect(), vec![]);
+    for layer in 0..layers - 1 {
+        for from in [2 * layer, 2 * layer + 1] {
+            graph.one_way_connect_nodes(from, 2 * layer + 2, 1);
+            graph.one_way_connect_nodes(from, 2 * layer + 3, 1);
+        }
+    }
+
+    let dag = graph.shortest_path_dag(0).unwrap();
+
+    assert_eq!(dag.path_count(2 * 63), PathCount::Finite(1 << 62));
+    assert_eq!(dag.path_count(2 * (layers - 1)), PathCount::Saturated);
+    assert!(!dag.is_unique(2 * (layers - 1)));
+}
@@ -0,0 +1,91 @@
+use crate::graph::Graph;
+use crate::path::Path;
+
+const WIDTH: u32 = 5;
+
+// A grid where a node's id encodes its position, so turns can be judged by direction
+fn create_grid() -> Graph<u32, u32> {
+    let mut graph = Graph::<u32, u32>::from_sparse((0..WIDTH * WIDTH).collect(), vec![]);
+
+    for i in 0..WIDTH {
+        for j in 0..WIDTH {
+            if i + 1 < WIDTH { graph.connect_nodes(i * WIDTH + j, (i + 1) * WIDTH + j, 1); }
+            if j + 1 < WIDTH { graph.connect_nodes(i * WIDTH + j, i * WIDTH + j + 1, 1); }
+        }
+    }
+
+    graph
+}
+
+fn turns(path : &[u32]) -> usize {
+    path.windows(3).filter(|t| t[1].abs_diff(t[0]) != t[2].abs_diff(t[1])).count()
+}
+
+#[test]
+fn without_penalties_matches_dijkstra() {
+    let graph = create_grid();
+    let goal = WIDTH * WIDTH - 1;
+
+    let path = graph.shortest_path_with_turns(0, goal, |_| 0, |_, _, _| 0).unwrap().unwrap();
+
+    assert_eq!(Some(path.total_cost()), graph.dijkstra(0).unwrap().distance(goal));
+    assert_eq!((path.start(), path.end()), (0, goal));
+}
+
+#[test]
+fn turn_penalty_straightens_path() {
+    let graph = create_grid();
+    let goal = WIDTH * WIDTH - 1;
+    let penalty = |a : u32, b : u32, c : u32| if b.abs_diff(a) != c.abs_diff(b) { 10 } else { 0 };
+
+    let path = graph.shortest_path_with_turns(0, goal, |_| 0, penalty).unwrap().unwrap();
+
+    assert_eq!(turns(path.nodes()), 1);
+    assert_eq!(path.total_cost(), 2 * (WIDTH - 1) + 10);
+}
+
+#[test]
+fn node_costs_avoid_junctions() {
+    let mut graph = Graph::<u16, f32>::from_sparse((0..5).collect(), vec![]);
+    graph.connect_nodes(0, 1, 1.0);
+    graph.connect_nodes(1, 4, 1.0);
+    graph.connect_nodes(0, 2, 1.5);
+    graph.connect_nodes(2, 3, 0.5);
+    graph.connect_nodes(3, 4, 0.5);
+
+    let junction = |id| if id == 1 { 5.0 } else { 0.0 };
+    let path = graph.shortest_path_with_turns(0, 4, junction, |_, _, _| 0.0).unwrap().unwrap();
+
+    assert_eq!(path.nodes(), [0, 2, 3, 4]);
+    assert_eq!(path.total_cost(), 2.5);
+
+    // The endpoints pay nothing
+    assert_eq!(graph.shortest_path_with_turns(1, 4, junction, |_, _, _| 0.0).unwrap().unwrap().costs(), [1.0]);
+}
+
+#[test]
+fn node_may_be_revisited_from_another_direction() {
+    // Reaching 3 directly from 1 means a forbidden turn, so the path loops round 2 to re-enter 1
+    let mut graph = Graph::<u16, u32>::from_sparse((0..4).collect(), vec![]);
+    graph.connect_nodes(0, 1, 1);
+    graph.connect_nodes(1, 2, 1);
+    graph.one_way_connect_nodes(2, 1, 1);
+    graph.one_way_connect_nodes(1, 3, 1);
+
+    let turn = |a, _, c| if a == 0 && c == 3 { 100 } else { 0 };
+    let path = graph.shortest_path_with_turns(0, 3, |_| 0, turn).unwrap().unwrap();
+
+    assert_eq!(path.nodes(), [0, 1, 2, 1, 3]);
+    assert_eq!(path.total_cost(), 4);
+}
+
+#[test]
+fn unreachable_and_negative() {
+    let mut graph = Graph::<u16, i32>::from_sparse((0..4).collect(), vec![]);
+    graph.connect_nodes(0, 1, 1);
+    graph.connect_nodes(1, 2, 1);
+
+    assert_eq!(graph.shortest_path_with_turns(0, 3, |_| 0, |_, _, _| 0).unwrap(), None);
+    assert_eq!(graph.shortest_path_with_turns(0, 0, |_| 0, |_, _, _| 0).unwrap(), Some(Path::new(0)));
+    assert!(graph.shortest_path_with_turns(0, 2, |_| -5, |_, _, _| 0).is_err());
+}
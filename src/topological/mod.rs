@@ -0,0 +1 @@
+pub mod topological_sort;
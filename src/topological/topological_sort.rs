@@ -0,0 +1,181 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+
+use nohash_hasher::{IntMap, IntSet};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::fmt;
+
+/*
+    Topological ordering
+
+    Orders the nodes so that every Go edge points forwards. Only graphs whose Go
+    edges are acyclic can be ordered - note that connect_nodes makes a Go edge 
+    each way, which is a cycle of two. Graphs built with one_way_connect_nodes 
+    are the usual candidates.
+
+*/
+
+/// Returned when a graph cannot be ordered. Holds one cycle, where each node has a Go edge
+/// to the next and the last has a Go edge back to the first.
+#[derive(PartialEq, Clone, Debug)]
+pub struct CycleError<Id> {
+    pub cycle: Vec<Id>
+}
+
+impl<Id : fmt::Debug> fmt::Display for CycleError<Id> {
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "graph contains a cycle: {:?}", self.cycle)
+    }
+}
+
+impl<Id : fmt::Debug> std::error::Error for CycleError<Id> {}
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{ 
+    /// Orders the graph with Kahn's algorithm. Ties are broken in no particular order.
+    pub fn topological_sort(&self) -> Result<Vec<Id>, CycleError<Id>> {
+        let mut in_degrees = self.in_degrees();
+        let mut queue : VecDeque<Id> = in_degrees.iter().filter(|(_, &d)| d == 0).map(|(&id, _)| id).collect();
+        let mut order = Vec::with_capacity(self.nodes.len());
+
+        while let Some(id) = queue.pop_front() {
+            order.push(id);
+            self.release(id, &mut in_degrees, |next| queue.push_back(next));
+        }
+
+        self.check_ordered(order, &in_degrees)
+    }
+
+    /// Orders the graph by Kahn's algorithm, always taking the smallest available id next - 
+    /// giving the lexicographically smallest order.
+    pub fn lexicographic_topological_sort(&self) -> Result<Vec<Id>, CycleError<Id>> where Id : Ord {
+        let mut in_degrees = self.in_degrees();
+        let mut heap : BinaryHeap<Reverse<Id>> = in_degrees.iter().filter(|(_, &d)| d == 0).map(|(&id, _)| Reverse(id)).collect();
+        let mut order = Vec::with_capacity(self.nodes.len());
+
+        while let Some(Reverse(id)) = heap.pop() {
+            order.push(id);
+            self.release(id, &mut in_degrees, |next| heap.push(Reverse(next)));
+        }
+
+        self.check_ordered(order, &in_degrees)
+    }
+
+    /// Groups the graph into generations - every node in a generation only depends on (has Go edges
+    /// from) nodes in earlier generations, so a whole generation can be processed at once.
+    pub fn topological_generations(&self) -> Result<Vec<Vec<Id>>, CycleError<Id>> {
+        let mut in_degrees = self.in_degrees();
+        let mut generation : Vec<Id> = in_degrees.iter().filter(|(_, &d)| d == 0).map(|(&id, _)| id).collect();
+        let mut generations = Vec::new();
+        let mut count = 0;
+
+        while !generation.is_empty() {
+            let mut next = Vec::new();
+            for &id in generation.iter() {
+                self.release(id, &mut in_degrees, |n| next.push(n));
+            }
+
+            count += generation.len();
+            generations.push(generation);
+            generation = next;
+        }
+
+        match count == self.nodes.len() {
+            true => Ok(generations),
+            false => Err(CycleError { cycle: self.find_cycle(&in_degrees) })
+        }
+    }
+
+    /// Orders the graph by reverse depth-first post-order. Ties are broken in no particular order.
+    pub fn df_topological_sort(&self) -> Result<Vec<Id>, CycleError<Id>> {
+        // Nodes on the current branch map to their position in path
+        let mut on_path = IntMap::<Id, usize>::default();
+        let mut finished = IntSet::<Id>::default();
+        let mut order = Vec::with_capacity(self.nodes.len());
+
+        for &root in self.nodes.keys() {
+            if finished.contains(&root) { continue; }
+
+            let mut path = vec![root];
+            let mut options = vec![self.nodes[&root].neighbours().cloned().collect::<Vec<_>>()];
+            on_path.insert(root, 0);
+
+            while let Some(remaining) = options.last_mut() {
+                match remaining.pop() {
+                    Some(next) if on_path.contains_key(&next) => {
+                        return Err(CycleError { cycle: path[on_path[&next]..].to_vec() });
+                    },
+                    Some(next) if !finished.contains(&next) => {
+                        on_path.insert(next, path.len());
+                        path.push(next);
+                        options.push(self.nodes[&next].neighbours().cloned().collect());
+                    },
+                    Some(_) => (),
+                    None => {
+                        let id = path.pop().expect("path and options are kept the same length");
+                        options.pop();
+                        on_path.remove(&id);
+                        finished.insert(id);
+                        order.push(id);
+                    }
+                }
+            }
+        }
+
+        order.reverse();
+        Ok(order)
+    }
+
+    /// Counts the Go edges arriving at each node
+    fn in_degrees(&self) -> IntMap<Id, usize> {
+        let mut in_degrees : IntMap<Id, usize> = self.nodes.keys().map(|&id| (id, 0)).collect();
+
+        for node in self.nodes.values() {
+            for to in node.neighbours() {
+                *in_degrees.get_mut(to).expect("Edge holds an incorrect id") += 1;
+            }
+        }
+
+        in_degrees
+    }
+
+    /// Removes the Go edges leaving id from the in-degree counts, handing on any node left with none
+    fn release<F : FnMut(Id)>(&self, id : Id, in_degrees : &mut IntMap<Id, usize>, mut ready : F) {
+        for to in self.nodes[&id].neighbours() {
+            let degree = in_degrees.get_mut(to).expect("Edge holds an incorrect id");
+            *degree -= 1;
+            if *degree == 0 { ready(*to); }
+        }
+    }
+
+    fn check_ordered(&self, order : Vec<Id>, in_degrees : &IntMap<Id, usize>) -> Result<Vec<Id>, CycleError<Id>> {
+        match order.len() == self.nodes.len() {
+            true => Ok(order),
+            false => Err(CycleError { cycle: self.find_cycle(in_degrees) })
+        }
+    }
+
+    /// Every node Kahn's algorithm could not release still has an edge arriving from another
+    /// such node, so walking backwards along those edges must eventually loop.
+    fn find_cycle(&self, in_degrees : &IntMap<Id, usize>) -> Vec<Id> {
+        let stuck = |id : &Id| in_degrees[id] > 0;
+
+        let mut current = *in_degrees.keys().find(|id| stuck(id)).expect("A cycle must remain");
+        let mut seen = IntMap::<Id, usize>::default();
+        let mut walk = Vec::new();
+
+        while !seen.contains_key(&current) {
+            seen.insert(current, walk.len());
+            walk.push(current);
+            current = self.predecessors(current)
+                .into_iter()
+                .find(stuck)
+                .expect("A stuck node must have a stuck predecessor");
+        }
+
+        let mut cycle = walk.split_off(seen[&current]);
+        cycle.reverse();
+        cycle
+    }
+}
@@ -0,0 +1,67 @@
+use crate::types::Identity;
+
+use nohash_hasher::IntMap;
+use std::collections::VecDeque;
+
+/*
+    Traversal context
+
+    Buffers for running many traversals back to back without reallocating. 
+    Visited nodes are stamped with the generation of the traversal that visited 
+    them, so starting a new traversal is a counter increment rather than a clear.
+
+*/
+
+pub struct TraversalContext<Id> where Id : Identity
+{
+    marks: IntMap<Id, u32>,
+    generation: u32,
+    pub(crate) queue: VecDeque<Id>,
+    pub(crate) stack: Vec<Id>
+}
+
+impl<Id> TraversalContext<Id> where Id : Identity
+{
+    pub fn new() -> TraversalContext<Id> {
+        TraversalContext { marks: IntMap::default(), generation: 0, queue: VecDeque::new(), stack: Vec::new() }
+    }
+
+    /// Preallocates room for traversals over num_nodes nodes
+    pub fn with_capacity(num_nodes : usize) -> TraversalContext<Id> {
+        let mut marks = IntMap::default();
+        marks.reserve(num_nodes);
+
+        TraversalContext { marks, generation: 0, queue: VecDeque::with_capacity(num_nodes), stack: Vec::with_capacity(num_nodes) }
+    }
+
+    /// Forgets the previous traversal, keeping the allocations
+    pub(crate) fn begin(&mut self) {
+        self.queue.clear();
+        self.stack.clear();
+
+        self.generation = self.generation.wrapping_add(1);
+
+        // Stale marks could collide with the new generation once the counter wraps
+        if self.generation == 0 {
+            self.marks.clear();
+            self.generation = 1;
+        }
+    }
+
+    /// Marks id as visited by the current traversal, returning false if it already was
+    pub(crate) fn visit(&mut self, id : Id) -> bool {
+        self.marks.insert(id, self.generation) != Some(self.generation)
+    }
+
+    /// Returns true if id has been visited by the current traversal
+    pub fn visited(&self, id : &Id) -> bool {
+        self.marks.get(id) == Some(&self.generation)
+    }
+}
+
+impl<Id> Default for TraversalContext<Id> where Id : Identity
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,51 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+use crate::traversal_context::context::TraversalContext;
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{ 
+    /// Returns a depth first iterator identical to df_iter, but using the buffers held
+    /// in context rather than allocating its own.
+    /// Will return an empty iterator if graph does not contain node of id
+    pub fn df_iter_in<'a>(&'a self, context : &'a mut TraversalContext<Id>, id : Id) -> DepthFirstContextIter<'a, Id, Cost> {
+        DepthFirstContextIter::<Id, Cost>::new(id, self, context)
+    }
+}
+
+pub struct DepthFirstContextIter<'a, Id, Cost> where Id : Identity
+{
+    graph: &'a Graph<Id, Cost>,
+    context: &'a mut TraversalContext<Id>
+}
+
+impl<'a, Id, Cost> DepthFirstContextIter<'a, Id, Cost> where Id : Identity
+{
+    pub fn new(id : Id, graph : &'a Graph<Id, Cost>, context : &'a mut TraversalContext<Id>) -> DepthFirstContextIter<'a, Id, Cost> {
+        context.begin();
+        context.visit(id);
+
+        if graph.nodes.contains_key(&id) {
+            context.stack.push(id);
+        }
+
+        DepthFirstContextIter { graph, context }
+    }
+}
+
+impl<'a, Id, Cost> Iterator for DepthFirstContextIter<'a, Id, Cost> where Id : Identity
+{
+    type Item = Id;
+
+    fn next(&mut self) -> Option<Self::Item> {
+
+        let next = self.context.stack.pop()?;
+
+        for &neighbour in self.graph.nodes[&next].neighbours() {
+            if self.context.visit(neighbour) {
+                self.context.stack.push(neighbour);
+            }
+        }
+
+        Some(next)
+    }
+}
@@ -0,0 +1,3 @@
+pub mod context;
+pub mod breadth_first_context_iter;
+pub mod depth_first_context_iter;
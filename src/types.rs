@@ -1,5 +1,6 @@
 use std::fmt::Debug;
 use std::hash::Hash;
+use std::ops::{Add, Sub};
 
 use nohash_hasher::IsEnabled;
 
@@ -17,17 +18,28 @@ impl Identity for i32 {}
 impl Identity for i64 {}
 impl Identity for isize {}
 
-pub trait Scalar : Clone + Copy {}
+/// Types available for use as edge costs within the graph. 
+/// The default value is taken as zero cost.
+pub trait Scalar : Clone + Copy + Debug + Default + PartialOrd + Add<Output = Self> + Sub<Output = Self> {
+    /// Lossy conversion, for comparing costs against plain numbers
+    fn as_f64(self) -> f64;
+    /// The exact bit pattern, for hashing costs
+    fn to_bits(self) -> u64;
+}
+
+macro_rules! impl_scalar {
+    (float $($t:ty),*) => { $( impl Scalar for $t { 
+        fn as_f64(self) -> f64 { self as f64 } 
+        fn to_bits(self) -> u64 { <$t>::to_bits(self) as u64 } 
+    } )* };
+    (integer $($t:ty),*) => { $( impl Scalar for $t { 
+        fn as_f64(self) -> f64 { self as f64 } 
+        fn to_bits(self) -> u64 { self as u64 } 
+    } )* };
+}
 
 //impl Scalar for f16 {}
-impl Scalar for f32 {}
-impl Scalar for f64 {}
+impl_scalar!(float f32, f64);
 //impl Scalar for f128 {}
-impl Scalar for i8 {}
-impl Scalar for i16 {}
-impl Scalar for i32 {}
-impl Scalar for i64 {}
-impl Scalar for u8 {}
-impl Scalar for u16 {}
-impl Scalar for u32 {}
-impl Scalar for u64 {}
\ No newline at end of file
+impl_scalar!(integer i8, i16, i32, i64);
+impl_scalar!(integer u8, u16, u32, u64);
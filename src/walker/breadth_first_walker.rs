@@ -0,0 +1,69 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+
+use queues::*;
+use nohash_hasher::IntSet;
+
+/*
+    Breadth-first walker
+
+    A detached breadth-first traversal. The walker owns its queue and visited set,
+    and only borrows the graph for the duration of each step - so the graph can be
+    mutated between steps.
+
+*/
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{
+    /// Returns a breadth first walker starting at id. 
+    /// Will return an empty walker if graph does not contain node of id
+    pub fn bf_walker(&self, id : Id) -> BreadthFirstWalker<Id> {
+        BreadthFirstWalker::<Id>::new(id, self)
+    }
+}
+
+pub struct BreadthFirstWalker<Id> where Id : Identity
+{
+    queue: Queue<Id>,
+    set: IntSet::<Id>
+}
+
+impl<Id> BreadthFirstWalker<Id> where Id : Identity
+{
+    pub fn new<Cost>(id : Id, graph : &Graph<Id, Cost>) -> BreadthFirstWalker<Id> {
+        let mut queue : Queue<Id> = queue![];
+        if graph.nodes.contains_key(&id) {
+            queue.add(id).expect("Failed to construct queue");
+        }
+
+        let mut set = IntSet::<Id>::default();
+        set.insert(id);
+
+        BreadthFirstWalker { queue, set }
+    }
+
+    /// Takes the next node of the walk. The neighbours of the returned node are queued
+    /// immediately, so removing the returned node before the next step does not cut
+    /// the walk short. Queued nodes that no longer exist in the graph are skipped.
+    pub fn next<Cost>(&mut self, graph : &Graph<Id, Cost>) -> Option<Id> {
+
+        // skip over anything that has been removed since it was queued
+        while let Ok(id) = self.queue.remove() {
+            if let Some(node) = graph.nodes.get(&id) {
+                for &neighbour in node.neighbours() {
+                    if self.set.insert(neighbour) {
+                        self.queue.add(neighbour).expect("Failed to add to queue");
+                    }
+                }
+                return Some(id);
+            }
+        }
+
+        None
+    }
+
+    /// Returns true if the node has already been queued or walked.
+    pub fn visited(&self, id : &Id) -> bool {
+        self.set.contains(id)
+    }
+}
@@ -0,0 +1,68 @@
+use crate::types::{ Scalar, Identity };
+use crate::graph::Graph;
+
+use nohash_hasher::IntSet;
+
+/*
+    Depth-first walker
+
+    A detached depth-first traversal. The walker owns its stack and visited set,
+    and only borrows the graph for the duration of each step - so the graph can be
+    mutated between steps.
+
+*/
+
+impl<Id, Cost> Graph<Id, Cost> where Id : Identity, Cost : Scalar
+{
+    /// Returns a depth first walker starting at id. 
+    /// Will return an empty walker if graph does not contain node of id
+    pub fn df_walker(&self, id : Id) -> DepthFirstWalker<Id> {
+        DepthFirstWalker::<Id>::new(id, self)
+    }
+}
+
+pub struct DepthFirstWalker<Id> where Id : Identity
+{
+    stack: Vec<Id>,
+    set: IntSet::<Id>
+}
+
+impl<Id> DepthFirstWalker<Id> where Id : Identity
+{
+    pub fn new<Cost>(id : Id, graph : &Graph<Id, Cost>) -> DepthFirstWalker<Id> {
+        let mut stack = Vec::<Id>::new();
+        if graph.nodes.contains_key(&id) {
+            stack.push(id);
+        }
+
+        let mut set = IntSet::<Id>::default();
+        set.insert(id);
+
+        DepthFirstWalker { stack, set }
+    }
+
+    /// Takes the next node of the walk. The neighbours of the returned node are stacked
+    /// immediately, so removing the returned node before the next step does not cut
+    /// the walk short. Stacked nodes that no longer exist in the graph are skipped.
+    pub fn next<Cost>(&mut self, graph : &Graph<Id, Cost>) -> Option<Id> {
+
+        // skip over anything that has been removed since it was stacked
+        while let Some(id) = self.stack.pop() {
+            if let Some(node) = graph.nodes.get(&id) {
+                for &neighbour in node.neighbours() {
+                    if self.set.insert(neighbour) {
+                        self.stack.push(neighbour);
+                    }
+                }
+                return Some(id);
+            }
+        }
+
+        None
+    }
+
+    /// Returns true if the node has already been stacked or walked.
+    pub fn visited(&self, id : &Id) -> bool {
+        self.set.contains(id)
+    }
+}
@@ -0,0 +1,2 @@
+pub mod breadth_first_walker;
+pub mod depth_first_walker;